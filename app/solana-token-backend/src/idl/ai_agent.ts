@@ -10,11 +10,26 @@ export type AiAgent = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "admin",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "rent",
           "isMut": false,
@@ -28,14 +43,31 @@ export type AiAgent = {
       ],
       "args": [
         {
-          "name": "fee",
-          "type": "f64"
+          "name": "fees",
+          "type": {
+            "defined": "FeeSettings"
+          }
+        },
+        {
+          "name": "curveLimits",
+          "type": {
+            "defined": "CurveLimits"
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
         }
       ]
     },
     {
       "name": "createPool",
       "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
@@ -51,6 +83,11 @@ export type AiAgent = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "payer",
           "isMut": true,
@@ -77,11 +114,35 @@ export type AiAgent = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "virtualSolReserves",
+          "type": "u64"
+        },
+        {
+          "name": "proportion",
+          "type": "u64"
+        },
+        {
+          "name": "initialLamports",
+          "type": "u64"
+        },
+        {
+          "name": "curveKind",
+          "type": {
+            "defined": "CurveKind"
+          }
+        }
+      ]
     },
     {
       "name": "addLiquidity",
       "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
@@ -112,6 +173,11 @@ export type AiAgent = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "user",
           "isMut": false,
@@ -229,6 +295,39 @@ export type AiAgent = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "userTokenAccount",
           "isMut": true,
@@ -258,17 +357,16 @@ export type AiAgent = {
           "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "teamAccount",
-          "isMut": true,
-          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
         }
       ]
     },
@@ -300,6 +398,39 @@ export type AiAgent = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "userTokenAccount",
           "isMut": true,
@@ -329,11 +460,6 @@ export type AiAgent = {
           "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "teamAccount",
-          "isMut": true,
-          "isSigner": false
         }
       ],
       "args": [
@@ -344,151 +470,15 @@ export type AiAgent = {
         {
           "name": "bump",
           "type": "u8"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "curveConfiguration",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fees",
-            "type": "f64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "liquidityPool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "token",
-            "type": "publicKey"
-          },
-          {
-            "name": "totalSupply",
-            "type": "u64"
-          },
-          {
-            "name": "reserveToken",
-            "type": "u64"
-          },
-          {
-            "name": "reserveSol",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "DuplicateTokenNotAllowed",
-      "msg": "Duplicate tokens are not allowed"
-    },
-    {
-      "code": 6001,
-      "name": "FailedToAllocateShares",
-      "msg": "Failed to allocate shares"
-    },
-    {
-      "code": 6002,
-      "name": "FailedToDeallocateShares",
-      "msg": "Failed to deallocate shares"
-    },
-    {
-      "code": 6003,
-      "name": "InsufficientShares",
-      "msg": "Insufficient shares"
-    },
-    {
-      "code": 6004,
-      "name": "InsufficientFunds",
-      "msg": "Insufficient funds to swap"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidAmount",
-      "msg": "Invalid amount to swap"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidFee",
-      "msg": "Invalid fee"
-    },
-    {
-      "code": 6007,
-      "name": "FailedToAddLiquidity",
-      "msg": "Failed to add liquidity"
-    },
-    {
-      "code": 6008,
-      "name": "FailedToRemoveLiquidity",
-      "msg": "Failed to remove liquidity"
-    },
-    {
-      "code": 6009,
-      "name": "NotEnoughToRemove",
-      "msg": "Sold token is not enough to remove pool"
-    },
-    {
-      "code": 6010,
-      "name": "NotCreator",
-      "msg": "Not a pool creator"
-    },
-    {
-      "code": 6011,
-      "name": "OverflowOrUnderflowOccurred",
-      "msg": "Overflow or underflow occured"
-    },
-    {
-      "code": 6012,
-      "name": "TokenAmountToSellTooBig",
-      "msg": "Token amount is too big to sell"
-    },
-    {
-      "code": 6013,
-      "name": "NotEnoughSolInVault",
-      "msg": "SOL is not enough in vault"
-    },
-    {
-      "code": 6014,
-      "name": "NotEnoughTokenInVault",
-      "msg": "Token is not enough in vault"
-    },
-    {
-      "code": 6015,
-      "name": "NegativeNumber",
-      "msg": "Amount is negative"
     },
     {
-      "code": 6016,
-      "name": "InvalidFeePercentage",
-      "msg": "Invalid fee percentage. It must be between 0.0 and 100.0."
-    }
-  ]
-};
-
-export const IDL: AiAgent = {
-  "version": "0.1.0",
-  "name": "ai_agent",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "buyExactOut",
       "accounts": [
         {
           "name": "dexConfigurationAccount",
@@ -496,110 +486,66 @@ export const IDL: AiAgent = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "pool",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "tokenMint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "fee",
-          "type": "f64"
-        }
-      ]
-    },
-    {
-      "name": "createPool",
-      "accounts": [
-        {
-          "name": "pool",
+          "name": "poolTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMint",
+          "name": "poolSolVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolTokenAccount",
+          "name": "creatorFeeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "protocolTreasury",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "rent",
+          "name": "feeDiscount",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "systemProgram",
+          "name": "platformTokenAccount",
           "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "addLiquidity",
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
-          "name": "platformTokenAccount",
-          "isMut": true,
+          "name": "blockedWallet",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolSolVault",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "platformAuthority",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "user",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -623,11 +569,25 @@ export const IDL: AiAgent = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxSolIn",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "removeLiquidity",
+      "name": "sellExactOut",
       "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
@@ -644,12 +604,45 @@ export const IDL: AiAgent = {
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
+          "name": "poolSolVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolSolVault",
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -680,6 +673,14 @@ export const IDL: AiAgent = {
         }
       ],
       "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxTokensIn",
+          "type": "u64"
+        },
         {
           "name": "bump",
           "type": "u8"
@@ -687,84 +688,123 @@ export const IDL: AiAgent = {
       ]
     },
     {
-      "name": "buy",
+      "name": "quoteBuy",
       "accounts": [
         {
           "name": "dexConfigurationAccount",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "pool",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "tokenMint",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "poolTokenAccount",
-          "isMut": true,
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "QuoteResult"
+      }
+    },
+    {
+      "name": "quoteSell",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolSolVault",
-          "isMut": true,
+          "name": "pool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount",
-          "isMut": true,
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "QuoteResult"
+      }
+    },
+    {
+      "name": "syncReserves",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "user",
+          "name": "pool",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "poolTokenAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "poolSolVault",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
-        },
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFeeRecipient",
+      "accounts": [
         {
-          "name": "teamAccount",
+          "name": "dexConfigurationAccount",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "feeRecipient",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "sell",
+      "name": "claimCreatorFees",
       "accounts": [
-        {
-          "name": "dexConfigurationAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "pool",
           "isMut": true,
@@ -772,99 +812,5079 @@ export const IDL: AiAgent = {
         },
         {
           "name": "tokenMint",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolTokenAccount",
+          "name": "creatorFeeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolSolVault",
+          "name": "creator",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerReferrer",
+      "accounts": [
         {
-          "name": "userTokenAccount",
+          "name": "referrer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "user",
+          "name": "wallet",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "referrer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawProtocolFees",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "protocolTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "teamAccount",
+          "name": "destination",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
           "name": "amount",
           "type": "u64"
-        },
-        {
-          "name": "bump",
-          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "curveConfiguration",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
+      "name": "setPoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverride",
+          "type": {
+            "option": {
+              "defined": "PoolFeeOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "discountBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setHolderDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "holderDiscount",
+          "type": {
+            "defined": "HolderDiscount"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": "ConfigUpdate"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "PauseMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPoolPaused",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "PauseMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": "ConfigUpdate"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "queuePoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverride",
+          "type": {
+            "option": {
+              "defined": "PoolFeeOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executePoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "queueFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "discountBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "executeFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPlatformAuthority",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removePlatformAuthority",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "blockWallet",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "allowSells",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "unblockWallet",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "launchToken",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "platformAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64"
+        },
+        {
+          "name": "proportion",
+          "type": "u64"
+        },
+        {
+          "name": "initialLamports",
+          "type": "u64"
+        },
+        {
+          "name": "curveKind",
+          "type": {
+            "defined": "CurveKind"
+          }
+        },
+        {
+          "name": "initialBuy",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "graduate",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "ammBuy",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solIn",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "ammSell",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokensIn",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLiquidity",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxTokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawLiquidity",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fees",
+          "type": {
+            "defined": "FeeSettings"
+          }
+        },
+        {
+          "name": "curveLimits",
+          "type": {
+            "defined": "CurveLimits"
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ammPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "reserveSol",
+            "type": "u64"
+          },
+          {
+            "name": "lpSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lockedLp",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "blockedWallet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowSells",
+            "type": "bool"
+          },
+          {
+            "name": "blockedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorFeeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveConfiguration",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fees",
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "curveLimits",
+            "type": {
+              "defined": "CurveLimits"
+            }
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFeesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscount",
+            "type": {
+              "defined": "HolderDiscount"
+            }
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "paused",
+            "type": {
+              "defined": "PauseMode"
+            }
+          },
+          {
+            "name": "configChangeDelay",
+            "type": "i64"
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "defined": "GraduationSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "token",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "reserveSol",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "proportion",
+            "type": "u64"
+          },
+          {
+            "name": "initialLamports",
+            "type": "u64"
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "defined": "CurveKind"
+            }
+          },
+          {
+            "name": "lastPrice",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeesAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "launchFee",
+            "type": {
+              "defined": "LaunchFeeSchedule"
+            }
+          },
+          {
+            "name": "launchSlot",
+            "type": "u64"
+          },
+          {
+            "name": "launchTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "PoolFeeOverride"
+              }
+            }
+          },
+          {
+            "name": "tradingPaused",
+            "type": {
+              "defined": "PauseMode"
+            }
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "defined": "GraduationSettings"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "PoolStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityProvider",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "ammPool",
+            "type": "publicKey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingConfigChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "type": {
+              "defined": "ConfigUpdate"
+            }
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingFeeDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingPoolFeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "PoolFeeOverride"
+              }
+            }
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "platformAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolTreasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "referredVolume",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "ConfigUpdate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fees",
+            "type": {
+              "option": {
+                "defined": "FeeSettings"
+              }
+            }
+          },
+          {
+            "name": "curveLimits",
+            "type": {
+              "option": {
+                "defined": "CurveLimits"
+              }
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "configChangeDelay",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "option": {
+                "defined": "GraduationSettings"
+              }
+            }
+          },
+          {
+            "name": "holderDiscount",
+            "type": {
+              "option": {
+                "defined": "HolderDiscount"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Quadratic"
+          },
+          {
+            "name": "ConstantProduct"
+          },
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVirtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "maxVirtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "minProportion",
+            "type": "u64"
+          },
+          {
+            "name": "maxProportion",
+            "type": "u64"
+          },
+          {
+            "name": "minInitialLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxInitialLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "sellFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "launchFee",
+            "type": {
+              "defined": "LaunchFeeSchedule"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GraduationMetric",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ReserveSol"
+          },
+          {
+            "name": "MarketCap"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GraduationSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metric",
+            "type": {
+              "defined": "GraduationMetric"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "ammFeeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HolderDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minBalance",
+            "type": "u64"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchClock",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Slots"
+          },
+          {
+            "name": "Seconds"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchDecay",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchFeeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "duration",
+            "type": "u64"
+          },
+          {
+            "name": "clock",
+            "type": {
+              "defined": "LaunchClock"
+            }
+          },
+          {
+            "name": "decay",
+            "type": {
+              "defined": "LaunchDecay"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PauseMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unpaused"
+          },
+          {
+            "name": "SellsOnly"
+          },
+          {
+            "name": "All"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolFeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "sellFeeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "Graduated"
+          },
+          {
+            "name": "Migrated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QuoteResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "reserveSol",
+            "type": "u64"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "priceLamportsE9",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "DuplicateTokenNotAllowed",
+      "msg": "Duplicate tokens are not allowed"
+    },
+    {
+      "code": 6001,
+      "name": "FailedToAllocateShares",
+      "msg": "Failed to allocate shares"
+    },
+    {
+      "code": 6002,
+      "name": "FailedToDeallocateShares",
+      "msg": "Failed to deallocate shares"
+    },
+    {
+      "code": 6003,
+      "name": "InsufficientShares",
+      "msg": "Insufficient shares"
+    },
+    {
+      "code": 6004,
+      "name": "InsufficientFunds",
+      "msg": "Insufficient funds to swap"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidAmount",
+      "msg": "Invalid amount to swap"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidFee",
+      "msg": "Invalid fee"
+    },
+    {
+      "code": 6007,
+      "name": "FailedToAddLiquidity",
+      "msg": "Failed to add liquidity"
+    },
+    {
+      "code": 6008,
+      "name": "FailedToRemoveLiquidity",
+      "msg": "Failed to remove liquidity"
+    },
+    {
+      "code": 6009,
+      "name": "NotEnoughToRemove",
+      "msg": "Sold token is not enough to remove pool"
+    },
+    {
+      "code": 6010,
+      "name": "NotCreator",
+      "msg": "Not a pool creator"
+    },
+    {
+      "code": 6011,
+      "name": "OverflowOrUnderflowOccurred",
+      "msg": "Overflow or underflow occured"
+    },
+    {
+      "code": 6012,
+      "name": "TokenAmountToSellTooBig",
+      "msg": "Token amount is too big to sell"
+    },
+    {
+      "code": 6013,
+      "name": "NotEnoughSolInVault",
+      "msg": "SOL is not enough in vault"
+    },
+    {
+      "code": 6014,
+      "name": "NotEnoughTokenInVault",
+      "msg": "Token is not enough in vault"
+    },
+    {
+      "code": 6015,
+      "name": "NegativeNumber",
+      "msg": "Amount is negative"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidFeePercentage",
+      "msg": "Invalid fee. It must be between 0 and 10000 basis points."
+    },
+    {
+      "code": 6017,
+      "name": "SlippageExceeded",
+      "msg": "Slippage tolerance exceeded"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidCurveParameters",
+      "msg": "Curve parameters are outside the configured limits"
+    },
+    {
+      "code": 6019,
+      "name": "ReservesOutOfSync",
+      "msg": "Pool reserves are not backed by the vault balances"
+    },
+    {
+      "code": 6020,
+      "name": "Unauthorized",
+      "msg": "Signer is not the configuration admin"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidFeeRecipient",
+      "msg": "Fee account does not match the configured fee recipient"
+    },
+    {
+      "code": 6022,
+      "name": "SelfReferral",
+      "msg": "Wallets cannot refer their own trades"
+    },
+    {
+      "code": 6023,
+      "name": "Paused",
+      "msg": "Trading is paused"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidConfigChangeDelay",
+      "msg": "Config change delay cannot be negative"
+    },
+    {
+      "code": 6025,
+      "name": "ConfigTimelocked",
+      "msg": "Configuration is timelocked. Queue the change instead"
+    },
+    {
+      "code": 6026,
+      "name": "ConfigChangeNotReady",
+      "msg": "Queued config change is not executable yet"
+    },
+    {
+      "code": 6027,
+      "name": "WalletBlocked",
+      "msg": "Wallet is blocked from trading"
+    },
+    {
+      "code": 6028,
+      "name": "PoolGraduated",
+      "msg": "Pool has graduated from its bonding curve"
+    },
+    {
+      "code": 6029,
+      "name": "PoolNotGraduated",
+      "msg": "Pool has not graduated to its AMM pool"
+    },
+    {
+      "code": 6030,
+      "name": "AlreadyMigrated",
+      "msg": "Account is not in the original layout"
+    }
+  ]
+};
+
+export const IDL: AiAgent = {
+  "version": "0.1.0",
+  "name": "ai_agent",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fees",
+          "type": {
+            "defined": "FeeSettings"
+          }
+        },
+        {
+          "name": "curveLimits",
+          "type": {
+            "defined": "CurveLimits"
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "virtualSolReserves",
+          "type": "u64"
+        },
+        {
+          "name": "proportion",
+          "type": "u64"
+        },
+        {
+          "name": "initialLamports",
+          "type": "u64"
+        },
+        {
+          "name": "curveKind",
+          "type": {
+            "defined": "CurveKind"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addLiquidity",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "buy",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sell",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "buyExactOut",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxSolIn",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sellExactOut",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxTokensIn",
+          "type": "u64"
+        },
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "quoteBuy",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "QuoteResult"
+      }
+    },
+    {
+      "name": "quoteSell",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "QuoteResult"
+      }
+    },
+    {
+      "name": "syncReserves",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFeeRecipient",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "claimCreatorFees",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerReferrer",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawProtocolFees",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverride",
+          "type": {
+            "option": {
+              "defined": "PoolFeeOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "discountBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setHolderDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "holderDiscount",
+          "type": {
+            "defined": "HolderDiscount"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": "ConfigUpdate"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "PauseMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPoolPaused",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "PauseMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": "ConfigUpdate"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelConfigChange",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfigChange",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "queuePoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverride",
+          "type": {
+            "option": {
+              "defined": "PoolFeeOverride"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executePoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPoolFeeOverride",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingPoolFeeOverride",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "queueFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "discountBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "executeFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelFeeDiscount",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingFeeDiscount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addPlatformAuthority",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removePlatformAuthority",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "blockWallet",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "wallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "allowSells",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "unblockWallet",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "launchToken",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "feeDiscount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "platformTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "platformAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "platformAuthorityEntry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64"
+        },
+        {
+          "name": "proportion",
+          "type": "u64"
+        },
+        {
+          "name": "initialLamports",
+          "type": "u64"
+        },
+        {
+          "name": "curveKind",
+          "type": {
+            "defined": "CurveKind"
+          }
+        },
+        {
+          "name": "initialBuy",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "graduate",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "ammBuy",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solIn",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "ammSell",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokensIn",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositLiquidity",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxTokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawLiquidity",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ammTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammSolVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "blockedWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        },
+        {
+          "name": "minTokensOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fees",
+          "type": {
+            "defined": "FeeSettings"
+          }
+        },
+        {
+          "name": "curveLimits",
+          "type": {
+            "defined": "CurveLimits"
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "dexConfigurationAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolSolVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ammPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "reserveSol",
+            "type": "u64"
+          },
+          {
+            "name": "lpSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lockedLp",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "blockedWallet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowSells",
+            "type": "bool"
+          },
+          {
+            "name": "blockedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorFeeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "curveConfiguration",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
             "name": "fees",
-            "type": "f64"
+            "type": {
+              "defined": "FeeSettings"
+            }
+          },
+          {
+            "name": "curveLimits",
+            "type": {
+              "defined": "CurveLimits"
+            }
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFeesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "holderDiscount",
+            "type": {
+              "defined": "HolderDiscount"
+            }
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "paused",
+            "type": {
+              "defined": "PauseMode"
+            }
+          },
+          {
+            "name": "configChangeDelay",
+            "type": "i64"
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "defined": "GraduationSettings"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "token",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "reserveSol",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "proportion",
+            "type": "u64"
+          },
+          {
+            "name": "initialLamports",
+            "type": "u64"
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "defined": "CurveKind"
+            }
+          },
+          {
+            "name": "lastPrice",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeesAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "launchFee",
+            "type": {
+              "defined": "LaunchFeeSchedule"
+            }
+          },
+          {
+            "name": "launchSlot",
+            "type": "u64"
+          },
+          {
+            "name": "launchTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "PoolFeeOverride"
+              }
+            }
+          },
+          {
+            "name": "tradingPaused",
+            "type": {
+              "defined": "PauseMode"
+            }
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "defined": "GraduationSettings"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "PoolStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityProvider",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "ammPool",
+            "type": "publicKey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingConfigChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "type": {
+              "defined": "ConfigUpdate"
+            }
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingFeeDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "discountBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingPoolFeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeOverride",
+            "type": {
+              "option": {
+                "defined": "PoolFeeOverride"
+              }
+            }
+          },
+          {
+            "name": "queuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "platformAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "liquidityPool",
+      "name": "protocolTreasury",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
             "type": "publicKey"
           },
           {
-            "name": "token",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "referredVolume",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeEarnings",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "ConfigUpdate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fees",
+            "type": {
+              "option": {
+                "defined": "FeeSettings"
+              }
+            }
+          },
+          {
+            "name": "curveLimits",
+            "type": {
+              "option": {
+                "defined": "CurveLimits"
+              }
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "configChangeDelay",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "graduation",
+            "type": {
+              "option": {
+                "defined": "GraduationSettings"
+              }
+            }
+          },
+          {
+            "name": "holderDiscount",
+            "type": {
+              "option": {
+                "defined": "HolderDiscount"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Quadratic"
+          },
+          {
+            "name": "ConstantProduct"
+          },
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVirtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "maxVirtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "minProportion",
+            "type": "u64"
+          },
+          {
+            "name": "maxProportion",
+            "type": "u64"
+          },
+          {
+            "name": "minInitialLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxInitialLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "sellFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "creatorFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralFeeShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "launchFee",
+            "type": {
+              "defined": "LaunchFeeSchedule"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GraduationMetric",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ReserveSol"
+          },
+          {
+            "name": "MarketCap"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GraduationSettings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "metric",
+            "type": {
+              "defined": "GraduationMetric"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "ammFeeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HolderDiscount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "platformMint",
             "type": "publicKey"
           },
           {
-            "name": "totalSupply",
+            "name": "minBalance",
             "type": "u64"
           },
           {
-            "name": "reserveToken",
+            "name": "discountBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchClock",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Slots"
+          },
+          {
+            "name": "Seconds"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchDecay",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchFeeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "duration",
+            "type": "u64"
+          },
+          {
+            "name": "clock",
+            "type": {
+              "defined": "LaunchClock"
+            }
+          },
+          {
+            "name": "decay",
+            "type": {
+              "defined": "LaunchDecay"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PauseMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unpaused"
+          },
+          {
+            "name": "SellsOnly"
+          },
+          {
+            "name": "All"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolFeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "sellFeeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "Graduated"
+          },
+          {
+            "name": "Migrated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QuoteResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
             "type": "u64"
           },
           {
@@ -872,8 +5892,12 @@ export const IDL: AiAgent = {
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "reserveToken",
+            "type": "u64"
+          },
+          {
+            "name": "priceLamportsE9",
+            "type": "u64"
           }
         ]
       }
@@ -963,7 +5987,77 @@ export const IDL: AiAgent = {
     {
       "code": 6016,
       "name": "InvalidFeePercentage",
-      "msg": "Invalid fee percentage. It must be between 0.0 and 100.0."
+      "msg": "Invalid fee. It must be between 0 and 10000 basis points."
+    },
+    {
+      "code": 6017,
+      "name": "SlippageExceeded",
+      "msg": "Slippage tolerance exceeded"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidCurveParameters",
+      "msg": "Curve parameters are outside the configured limits"
+    },
+    {
+      "code": 6019,
+      "name": "ReservesOutOfSync",
+      "msg": "Pool reserves are not backed by the vault balances"
+    },
+    {
+      "code": 6020,
+      "name": "Unauthorized",
+      "msg": "Signer is not the configuration admin"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidFeeRecipient",
+      "msg": "Fee account does not match the configured fee recipient"
+    },
+    {
+      "code": 6022,
+      "name": "SelfReferral",
+      "msg": "Wallets cannot refer their own trades"
+    },
+    {
+      "code": 6023,
+      "name": "Paused",
+      "msg": "Trading is paused"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidConfigChangeDelay",
+      "msg": "Config change delay cannot be negative"
+    },
+    {
+      "code": 6025,
+      "name": "ConfigTimelocked",
+      "msg": "Configuration is timelocked. Queue the change instead"
+    },
+    {
+      "code": 6026,
+      "name": "ConfigChangeNotReady",
+      "msg": "Queued config change is not executable yet"
+    },
+    {
+      "code": 6027,
+      "name": "WalletBlocked",
+      "msg": "Wallet is blocked from trading"
+    },
+    {
+      "code": 6028,
+      "name": "PoolGraduated",
+      "msg": "Pool has graduated from its bonding curve"
+    },
+    {
+      "code": 6029,
+      "name": "PoolNotGraduated",
+      "msg": "Pool has not graduated to its AMM pool"
+    },
+    {
+      "code": 6030,
+      "name": "AlreadyMigrated",
+      "msg": "Account is not in the original layout"
     }
   ]
 };
@@ -81,6 +81,15 @@ const program = new Program<AiAgent>(IDL, programId, readOnlyProvider);
 const curveSeed = "CurveConfiguration"
 const POOL_SEED_PREFIX = "liquidity_pool"
 const SOL_VAULT_PREFIX = "liquidity_sol_vault"
+const CREATOR_FEE_VAULT_PREFIX = "creator_fee_vault"
+const PROTOCOL_TREASURY_SEED = "protocol_treasury"
+const BLOCKED_WALLET_PREFIX = "blocked_wallet"
+const PLATFORM_AUTHORITY_PREFIX = "platform_authority"
+
+// Curve every pool created here trades on: the quadratic curve over a 25 SOL virtual reserve
+const VIRTUAL_SOL_RESERVES = new BN(25_000_000_000)
+const PROPORTION = new BN(1280)
+const INITIAL_LAMPORTS = new BN(10_000_000)
 function sleep(ms: number) {
   return new Promise(resolve => setTimeout(resolve, ms));
 }
@@ -90,7 +99,29 @@ const [curveConfig] = PublicKey.findProgramAddressSync(
   program.programId
 )
 
-const teamAccount = new PublicKey("6XF158v9uXWL7dpJnkJFHKpZgzmLXX5HoH4vG5hPsmmP")
+const [protocolTreasury] = PublicKey.findProgramAddressSync(
+  [Buffer.from(PROTOCOL_TREASURY_SEED)],
+  program.programId
+)
+
+const [platformAuthorityEntry] = PublicKey.findProgramAddressSync(
+  [Buffer.from(PLATFORM_AUTHORITY_PREFIX), platformWallet.publicKey.toBuffer()],
+  program.programId
+)
+
+function creatorFeeVaultPda(mint: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(CREATOR_FEE_VAULT_PREFIX), mint.toBuffer()],
+    program.programId
+  )[0]
+}
+
+function blockedWalletPda(wallet: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(BLOCKED_WALLET_PREFIX), wallet.toBuffer()],
+    program.programId
+  )[0]
+}
 
 
 
@@ -686,13 +717,16 @@ app.post("/create-add-liquidity-transaction", async (req, res) => {
       //     mintKeypair.publicKey               
       // ),
         await program.methods
-          .createPool()
+          .createPool(VIRTUAL_SOL_RESERVES, PROPORTION, INITIAL_LAMPORTS, { quadratic: {} })
           .accounts({
+            dexConfigurationAccount: curveConfig,
             pool: poolPda,
             tokenMint: mintKeypair.publicKey,
             poolTokenAccount: poolTokenAccount,
+            creatorFeeVault: creatorFeeVaultPda(mintKeypair.publicKey),
             payer: user,
             tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
             systemProgram: SystemProgram.programId
           })
@@ -700,12 +734,14 @@ app.post("/create-add-liquidity-transaction", async (req, res) => {
         await program.methods
           .addLiquidity()
           .accounts({
+          dexConfigurationAccount: curveConfig,
           pool: poolPda,
           poolSolVault: poolSolVault,
           tokenMint: mintKeypair.publicKey,
           poolTokenAccount: poolTokenAccount,
           platformTokenAccount: platformTokenAccount,
           platformAuthority: platformWallet.publicKey,
+          platformAuthorityEntry,
           user:user,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
@@ -715,12 +751,17 @@ app.post("/create-add-liquidity-transaction", async (req, res) => {
           .instruction(),
           // mintAuthorityInstruction,
         await program.methods
-          .buy(new BN(buyAmount.toString()))
+          .buy(new BN(buyAmount.toString()), new BN(0))
           .accounts({
             pool: poolPda,
             tokenMint: mintKeypair.publicKey,
-            teamAccount: teamAccount,
             poolSolVault,
+            creatorFeeVault: creatorFeeVaultPda(mintKeypair.publicKey),
+            protocolTreasury,
+            referrer: null,
+            feeDiscount: null,
+            platformTokenAccount: null,
+            blockedWallet: blockedWalletPda(user),
             poolTokenAccount: poolTokenAccount,
             userTokenAccount: userTokenAccount,
             dexConfigurationAccount: curveConfig,
@@ -767,7 +808,7 @@ app.post("/create-add-liquidity-transaction", async (req, res) => {
 app.post('/api/:tokenMint/buy', async (req: Request, res: Response) => {
   try {
     const { tokenMint } = req.params;
-    const {amount} = req.query
+    const {amount, minTokensOut} = req.query
     const {account} = req.body;
 
     if (!amount) {
@@ -824,12 +865,17 @@ app.post('/api/:tokenMint/buy', async (req: Request, res: Response) => {
       ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
       ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
       await program.methods
-        .buy(new BN(amountInLamports))
+        .buy(new BN(amountInLamports), new BN((minTokensOut as string) || 0))
         .accounts({
           pool: poolPda,
           tokenMint: mint,
-          teamAccount: teamAccount,
           poolSolVault,
+          creatorFeeVault: creatorFeeVaultPda(mint),
+          protocolTreasury,
+          referrer: null,
+          feeDiscount: null,
+          platformTokenAccount: null,
+          blockedWallet: blockedWalletPda(userPubkey),
           poolTokenAccount: poolTokenAccount,
           userTokenAccount: userTokenAccount,
           dexConfigurationAccount: curveConfig,
@@ -869,7 +915,7 @@ app.post('/api/:tokenMint/buy', async (req: Request, res: Response) => {
 app.post('/api/:tokenMint/sell', async (req: Request, res: Response) => {
   try {
     const { tokenMint } = req.params;
-    const { amount } = req.query;
+    const { amount, minSolOut } = req.query;
     const { account } = req.body;
       
     // console.log('Sell request:', { tokenMint, account, amount });
@@ -932,12 +978,17 @@ app.post('/api/:tokenMint/sell', async (req: Request, res: Response) => {
         ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
         ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
         await program.methods
-          .sell(tokenAmount, bump)
+          .sell(tokenAmount, bump, new BN((minSolOut as string) || 0))
           .accounts({
             pool: poolPda,
             tokenMint: mint,
-            teamAccount: teamAccount,
             poolSolVault,
+            creatorFeeVault: creatorFeeVaultPda(mint),
+            protocolTreasury,
+            referrer: null,
+            feeDiscount: null,
+            platformTokenAccount: null,
+            blockedWallet: blockedWalletPda(userPubkey),
             poolTokenAccount: poolTokenAccount,
             userTokenAccount: userTokenAccount,
             dexConfigurationAccount: curveConfig,
@@ -1174,7 +1225,7 @@ app.get('/blinks/:tokenMint', async (req: Request, res: Response) => {
 app.post('/api/blinks/:tokenMint/buy', async (req: Request, res: Response) => {
   try {
     const { tokenMint } = req.params;
-    const {amount} = req.query
+    const {amount, minTokensOut} = req.query
     const {account} = req.body;
 
     if (!amount) {
@@ -1228,12 +1279,17 @@ app.post('/api/blinks/:tokenMint/buy', async (req: Request, res: Response) => {
       ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
       ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
       await program.methods
-        .buy(new BN(amountInLamports))
+        .buy(new BN(amountInLamports), new BN((minTokensOut as string) || 0))
         .accounts({
           pool: poolPda,
           tokenMint: mint,
-          teamAccount: teamAccount,
           poolSolVault,
+          creatorFeeVault: creatorFeeVaultPda(mint),
+          protocolTreasury,
+          referrer: null,
+          feeDiscount: null,
+          platformTokenAccount: null,
+          blockedWallet: blockedWalletPda(userPubkey),
           poolTokenAccount: poolTokenAccount,
           userTokenAccount: userTokenAccount,
           dexConfigurationAccount: curveConfig,
@@ -1266,7 +1322,7 @@ app.post('/api/blinks/:tokenMint/buy', async (req: Request, res: Response) => {
 app.post('/api/blinks/:tokenMint/sell', async (req: Request, res: Response) => {
   try {
     const { tokenMint } = req.params;
-    const { amount, percentage } = req.query;
+    const { amount, percentage, minSolOut } = req.query;
     const { account } = req.body;
 
     if (!amount && !percentage) {
@@ -1328,12 +1384,17 @@ app.post('/api/blinks/:tokenMint/sell', async (req: Request, res: Response) => {
         ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 }),
         ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 200_000 }),
         await program.methods
-          .sell(tokenAmount, bump)
+          .sell(tokenAmount, bump, new BN((minSolOut as string) || 0))
           .accounts({
             pool: poolPda,
             tokenMint: mint,
-            teamAccount: teamAccount,
             poolSolVault,
+            creatorFeeVault: creatorFeeVaultPda(mint),
+            protocolTreasury,
+            referrer: null,
+            feeDiscount: null,
+            platformTokenAccount: null,
+            blockedWallet: blockedWalletPda(userPubkey),
             poolTokenAccount: poolTokenAccount,
             userTokenAccount: userTokenAccount,
             dexConfigurationAccount: curveConfig,
@@ -0,0 +1,131 @@
+//! wasm-bindgen bindings so the TypeScript services quote trades with `curve-math`.
+//! Build with `wasm-pack build crates/curve-math-wasm`.
+use curve_math::{CurveKind, FeeRate, LaunchDecay, MathError, PoolState, TradeQuote};
+use wasm_bindgen::prelude::*;
+
+// JS handle on a pool's pricing state. `curveKind` follows the program's `CurveKind`
+// order: 0 quadratic, 1 constant product, 2 linear, 3 exponential.
+#[wasm_bindgen(js_name = Pool)]
+pub struct WasmPool {
+    state: PoolState,
+}
+
+#[wasm_bindgen(js_name = Quote)]
+#[derive(Clone, Copy)]
+pub struct WasmQuote {
+    #[wasm_bindgen(js_name = solAmount)]
+    pub sol_amount: u64,
+    #[wasm_bindgen(js_name = tokenAmount)]
+    pub token_amount: u64,
+    pub fee: u64,
+}
+
+impl From<TradeQuote> for WasmQuote {
+    fn from(quote: TradeQuote) -> Self {
+        Self {
+            sol_amount: quote.sol_amount,
+            token_amount: quote.token_amount,
+            fee: quote.fee,
+        }
+    }
+}
+
+fn js_error(error: MathError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn fee_rate(bps: u16, min_lamports: u64) -> FeeRate {
+    FeeRate { bps, min_lamports }
+}
+
+#[wasm_bindgen(js_class = Pool)]
+impl WasmPool {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        curve_kind: u8,
+        total_supply: u64,
+        reserve_token: u64,
+        reserve_sol: u64,
+        virtual_sol_reserves: u64,
+        proportion: u64,
+    ) -> Result<WasmPool, JsError> {
+        let curve_kind = match curve_kind {
+            0 => CurveKind::Quadratic,
+            1 => CurveKind::ConstantProduct,
+            2 => CurveKind::Linear,
+            3 => CurveKind::Exponential,
+            _ => return Err(js_error(MathError::InvalidCurveParameters)),
+        };
+
+        Ok(Self {
+            state: PoolState {
+                curve_kind,
+                total_supply,
+                reserve_token,
+                reserve_sol,
+                virtual_sol_reserves,
+                proportion,
+            },
+        })
+    }
+
+    #[wasm_bindgen(js_name = quoteBuy)]
+    pub fn quote_buy(&self, amount: u64, fee_bps: u16, min_fee_lamports: u64) -> Result<WasmQuote, JsError> {
+        self.state
+            .quote_buy(amount, fee_rate(fee_bps, min_fee_lamports))
+            .map(Into::into)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = quoteBuyExactOut)]
+    pub fn quote_buy_exact_out(&self, token_amount: u64, fee_bps: u16, min_fee_lamports: u64) -> Result<WasmQuote, JsError> {
+        self.state
+            .quote_buy_exact_out(token_amount, fee_rate(fee_bps, min_fee_lamports))
+            .map(Into::into)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = quoteSell)]
+    pub fn quote_sell(&self, amount: u64, fee_bps: u16, min_fee_lamports: u64) -> Result<WasmQuote, JsError> {
+        self.state
+            .quote_sell(amount, fee_rate(fee_bps, min_fee_lamports))
+            .map(Into::into)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = quoteSellExactOut)]
+    pub fn quote_sell_exact_out(&self, sol_amount: u64, fee_bps: u16, min_fee_lamports: u64) -> Result<WasmQuote, JsError> {
+        self.state
+            .quote_sell_exact_out(sol_amount, fee_rate(fee_bps, min_fee_lamports))
+            .map(Into::into)
+            .map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = spotPrice)]
+    pub fn spot_price(&self, decimals: u8) -> Result<u64, JsError> {
+        self.state.spot_price(decimals).map_err(js_error)
+    }
+
+    #[wasm_bindgen(js_name = marketCap)]
+    pub fn market_cap(&self) -> Result<u64, JsError> {
+        self.state.market_cap().map_err(js_error)
+    }
+}
+
+// Fee in bps `elapsed` slots or seconds into a pool's launch window. `decay` follows the
+// program's `LaunchDecay` order: 0 linear, 1 exponential.
+#[wasm_bindgen(js_name = launchFeeBps)]
+pub fn launch_fee_bps(
+    base_bps: u16,
+    start_bps: u16,
+    elapsed: u64,
+    duration: u64,
+    decay: u8,
+) -> Result<u16, JsError> {
+    let decay = match decay {
+        0 => LaunchDecay::Linear,
+        1 => LaunchDecay::Exponential,
+        _ => return Err(js_error(MathError::InvalidFee)),
+    };
+    Ok(curve_math::launch_fee_bps(base_bps, start_bps, elapsed, duration, decay))
+}
@@ -0,0 +1,103 @@
+use crate::error::MathError;
+use crate::fees::{split_fee, FeeRate};
+use crate::math::{isqrt, mul_div_ceil, mul_div_floor};
+use crate::pool::scaled_token_unit;
+
+// Reserves of a graduated token's constant-product pool. The trade fee is left in the
+// reserves, so it grows `k` and accrues to LP shares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AmmState {
+    pub reserve_token: u64,
+    pub reserve_sol: u64,
+    pub lp_supply: u64,
+    pub fee_bps: u16,
+}
+
+impl AmmState {
+    // Shares minted for the liquidity a pool is opened with: the geometric mean of the reserves
+    pub fn initial_shares(reserve_token: u64, reserve_sol: u64) -> Result<u64, MathError> {
+        let shares = isqrt(reserve_token as u128 * reserve_sol as u128);
+        if shares == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        u64::try_from(shares).map_err(|_| MathError::Overflow)
+    }
+
+    // Reserves for a pool taking over from a bonding curve whose last spot price was `price`
+    // (lamports per whole token times `PRICE_SCALE`): all of `reserve_sol` and only as many of
+    // the `available` tokens as keep that price. If the curve's tokens run out first, the pool
+    // opens with all of them at a higher price.
+    pub fn opening(
+        reserve_sol: u64,
+        available: u64,
+        price: u64,
+        decimals: u8,
+        fee_bps: u16,
+    ) -> Result<AmmState, MathError> {
+        if price == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        let tokens = mul_div_floor(reserve_sol as u128, scaled_token_unit(decimals)? as u128, price as u128)?;
+        let reserve_token = tokens.min(available as u128) as u64;
+        let lp_supply = Self::initial_shares(reserve_token, reserve_sol)?;
+        Ok(AmmState { reserve_token, reserve_sol, lp_supply, fee_bps })
+    }
+
+    fn fee_rate(&self) -> FeeRate {
+        FeeRate { bps: self.fee_bps, min_lamports: 0 }
+    }
+
+    // Output of swapping `amount_in` into a pool holding `reserve_in` and `reserve_out`, after
+    // the fee is taken from the input
+    fn swap_out(&self, amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64, MathError> {
+        if amount_in == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        let (_, amount_in) = split_fee(amount_in, self.fee_rate())?;
+        let amount_out = mul_div_floor(
+            reserve_out as u128,
+            amount_in as u128,
+            reserve_in as u128 + amount_in as u128,
+        )?;
+        u64::try_from(amount_out).map_err(|_| MathError::Overflow)
+    }
+
+    pub fn quote_buy(&self, sol_in: u64) -> Result<u64, MathError> {
+        self.swap_out(sol_in, self.reserve_sol, self.reserve_token)
+    }
+
+    pub fn quote_sell(&self, tokens_in: u64) -> Result<u64, MathError> {
+        self.swap_out(tokens_in, self.reserve_token, self.reserve_sol)
+    }
+
+    // Tokens to pair with `sol_in` lamports in a deposit, and the shares minted for it. Tokens
+    // round up and shares round down so a deposit never dilutes existing providers.
+    pub fn quote_deposit(&self, sol_in: u64) -> Result<(u64, u64), MathError> {
+        if sol_in == 0 || self.reserve_sol == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        let token_in = mul_div_ceil(sol_in as u128, self.reserve_token as u128, self.reserve_sol as u128)?;
+        let shares = mul_div_floor(sol_in as u128, self.lp_supply as u128, self.reserve_sol as u128)?;
+        Ok((
+            u64::try_from(token_in).map_err(|_| MathError::Overflow)?,
+            u64::try_from(shares).map_err(|_| MathError::Overflow)?,
+        ))
+    }
+
+    // Tokens and lamports paid out for burning `shares`, both rounded down
+    pub fn quote_withdraw(&self, shares: u64) -> Result<(u64, u64), MathError> {
+        if shares == 0 || shares > self.lp_supply {
+            return Err(MathError::InvalidAmount);
+        }
+        let token_out = mul_div_floor(self.reserve_token as u128, shares as u128, self.lp_supply as u128)?;
+        let sol_out = mul_div_floor(self.reserve_sol as u128, shares as u128, self.lp_supply as u128)?;
+        Ok((token_out as u64, sol_out as u64))
+    }
+
+    // Lamports per whole token at the current reserves, times PRICE_SCALE
+    pub fn spot_price(&self, decimals: u8) -> Result<u64, MathError> {
+        let token_unit = scaled_token_unit(decimals)? as u128;
+        let price = mul_div_floor(self.reserve_sol as u128, token_unit, self.reserve_token as u128)?;
+        u64::try_from(price).map_err(|_| MathError::Overflow)
+    }
+}
@@ -0,0 +1,463 @@
+use alloc::boxed::Box;
+
+use crate::error::MathError;
+use crate::math::{
+    exp_fixed, isqrt, mul_div_ceil, mul_div_floor, EXP_ONE, LAMPORTS_PER_SOL, MAX_EXPONENT,
+    PROPORTION_DENOMINATOR, TOKEN_SCALE,
+};
+
+// Shape of the bonding curve a pool trades on. Every curve is parameterised by the pool's
+// `virtual_sol_reserves` (V) and `proportion` (P) over its `total_supply` (T):
+//
+// - Quadratic: the original launch curve. Cost is `((s + V * 1e6)^2 - (V * 1e6)^2) / (P * 1e21)`.
+// - ConstantProduct: `x * y = k` on virtual reserves of V lamports and `T * P / 1000` tokens,
+//   so P must be above 1000.
+// - Linear: price starts at V / T and rises linearly to `(1 + P / 1000)` times that at T.
+// - Exponential: price starts at V / T and grows by `e^(P / 1000)` across T, so P is capped at
+//   `MAX_EXPONENT * 1000`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveKind {
+    Quadratic,
+    ConstantProduct,
+    Linear,
+    Exponential,
+}
+
+impl CurveKind {
+    pub fn validate(&self, proportion: u64) -> Result<(), MathError> {
+        let valid = match self {
+            CurveKind::Quadratic | CurveKind::Linear => proportion > 0,
+            CurveKind::ConstantProduct => proportion > PROPORTION_DENOMINATOR,
+            CurveKind::Exponential => {
+                proportion > 0 && proportion as u128 <= MAX_EXPONENT * PROPORTION_DENOMINATOR as u128
+            }
+        };
+        if !valid {
+            return Err(MathError::InvalidCurveParameters);
+        }
+        Ok(())
+    }
+
+    pub fn curve(
+        &self,
+        total_supply: u64,
+        virtual_sol: u64,
+        proportion: u64,
+    ) -> Box<dyn BondingCurve> {
+        match self {
+            CurveKind::Quadratic => Box::new(QuadraticCurve { total_supply, virtual_sol, proportion }),
+            CurveKind::ConstantProduct => {
+                Box::new(ConstantProductCurve { total_supply, virtual_sol, proportion })
+            }
+            CurveKind::Linear => Box::new(LinearCurve { total_supply, virtual_sol, proportion }),
+            CurveKind::Exponential => {
+                Box::new(ExponentialCurve { total_supply, virtual_sol, proportion })
+            }
+        }
+    }
+}
+
+// A bonding curve prices trades by `cost`, the lamports it has taken in once `sold` tokens
+// have left the pool, rounded down, and `cost_ceil`, the same rounded up. Both must never
+// decrease as `sold` grows. The default trade maths is derived from them and rounds every
+// result in the pool's favour; curves with a closed form override it.
+pub trait BondingCurve {
+    fn total_supply(&self) -> u64;
+
+    fn cost(&self, sold: u64) -> Result<u128, MathError>;
+
+    fn cost_ceil(&self, sold: u64) -> Result<u128, MathError>;
+
+    // Tokens paid out for `sol_in` lamports, rounded down.
+    fn tokens_out_for_sol(&self, sold: u64, sol_in: u64) -> Result<u64, MathError> {
+        let available = self.available(sold)?;
+        let target = self
+            .cost(sold)?
+            .checked_add(sol_in as u128)
+            .ok_or(MathError::Overflow)?;
+
+        // Largest amount whose cost fits in `sol_in`.
+        let (mut low, mut high) = (0_u64, available);
+        while low < high {
+            let mid = high - (high - low) / 2;
+            if self.cost_ceil(sold + mid)? <= target {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        within_supply(self, sold, sol_in, low)
+    }
+
+    // Lamports the curve must receive to pay out exactly `token_out` tokens, rounded up.
+    fn sol_in_for_tokens(&self, sold: u64, token_out: u64) -> Result<u64, MathError> {
+        if token_out > self.available(sold)? {
+            return Err(MathError::NotEnoughTokenInVault);
+        }
+
+        let amount_in = self.cost_ceil(sold + token_out)? - self.cost(sold)?;
+        u64::try_from(amount_in).map_err(|_| MathError::Overflow)
+    }
+
+    // Lamports paid out for `token_in` tokens, rounded down.
+    fn sol_out_for_tokens(&self, sold: u64, token_in: u64) -> Result<u64, MathError> {
+        if token_in > sold {
+            return Err(MathError::TokenAmountToSellTooBig);
+        }
+
+        let amount_out = self.cost(sold)?.saturating_sub(self.cost_ceil(sold - token_in)?);
+        u64::try_from(amount_out).map_err(|_| MathError::Overflow)
+    }
+
+    // Tokens the curve must receive to pay out `sol_out` lamports, rounded up.
+    fn tokens_in_for_sol(&self, sold: u64, sol_out: u64) -> Result<u64, MathError> {
+        if sol_out == 0 {
+            return Ok(0);
+        }
+        let target = self
+            .cost(sold)?
+            .checked_sub(sol_out as u128)
+            .ok_or(MathError::NotEnoughSolInVault)?;
+        if self.cost_ceil(0)? > target {
+            return Err(MathError::NotEnoughSolInVault);
+        }
+
+        // Smallest amount whose refund covers `sol_out`.
+        let (mut low, mut high) = (0_u64, sold);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.cost_ceil(sold - mid)? <= target {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        Ok(low)
+    }
+
+    // Marginal price of `token_unit` tokens once `sold` are out, taken from the derivative of
+    // `cost` and rounded down.
+    fn spot_price(&self, sold: u64, token_unit: u64) -> Result<u128, MathError>;
+
+    fn available(&self, sold: u64) -> Result<u64, MathError> {
+        self.total_supply()
+            .checked_sub(sold)
+            .ok_or(MathError::Overflow)
+    }
+}
+
+// Caps a buy of `token_out` at the tokens left in the pool, unless `sol_in` is more than
+// buying all of them costs, which would leave the buyer overpaying.
+fn within_supply<C: BondingCurve + ?Sized>(
+    curve: &C,
+    sold: u64,
+    sol_in: u64,
+    token_out: u64,
+) -> Result<u64, MathError> {
+    let available = curve.available(sold)?;
+    if token_out < available {
+        return Ok(token_out);
+    }
+    if sol_in > curve.sol_in_for_tokens(sold, available)? {
+        return Err(MathError::NotEnoughTokenInVault);
+    }
+    Ok(available)
+}
+
+// Curves whose cost is `((sold + offset)^2 - offset^2) / denominator`. Working on squared
+// positions `x = sold + offset` turns every trade into a closed form: a move from `x0` to
+// `x1` is priced at `(x1^2 - x0^2) / denominator` lamports.
+trait SquareLaw {
+    fn supply(&self) -> u64;
+
+    fn offset(&self) -> Result<u128, MathError>;
+
+    fn denominator(&self) -> Result<u128, MathError>;
+
+    fn position(&self, sold: u64) -> Result<u128, MathError> {
+        self.offset()?
+            .checked_add(sold as u128)
+            .ok_or(MathError::Overflow)
+    }
+
+    fn squared(&self, sold: u64) -> Result<u128, MathError> {
+        let x = self.position(sold)?;
+        x.checked_mul(x)
+            .ok_or(MathError::Overflow)
+    }
+}
+
+impl<C: SquareLaw> BondingCurve for C {
+    fn total_supply(&self) -> u64 {
+        self.supply()
+    }
+
+    fn cost(&self, sold: u64) -> Result<u128, MathError> {
+        Ok((self.squared(sold)? - self.squared(0)?) / self.denominator()?)
+    }
+
+    fn cost_ceil(&self, sold: u64) -> Result<u128, MathError> {
+        Ok((self.squared(sold)? - self.squared(0)?).div_ceil(self.denominator()?))
+    }
+
+    fn tokens_out_for_sol(&self, sold: u64, sol_in: u64) -> Result<u64, MathError> {
+        let x1_squared = self
+            .denominator()?
+            .checked_mul(sol_in as u128)
+            .and_then(|delta| delta.checked_add(self.squared(sold).ok()?))
+            .ok_or(MathError::Overflow)?;
+
+        let amount_out = isqrt(x1_squared) - self.position(sold)?;
+        let amount_out = u64::try_from(amount_out).map_err(|_| MathError::Overflow)?;
+        within_supply(self, sold, sol_in, amount_out)
+    }
+
+    fn sol_in_for_tokens(&self, sold: u64, token_out: u64) -> Result<u64, MathError> {
+        if token_out > self.available(sold)? {
+            return Err(MathError::NotEnoughTokenInVault);
+        }
+
+        let delta = self.squared(sold + token_out)? - self.squared(sold)?;
+        let amount_in = delta.div_ceil(self.denominator()?);
+        u64::try_from(amount_in).map_err(|_| MathError::Overflow)
+    }
+
+    fn sol_out_for_tokens(&self, sold: u64, token_in: u64) -> Result<u64, MathError> {
+        if token_in > sold {
+            return Err(MathError::TokenAmountToSellTooBig);
+        }
+
+        let delta = self.squared(sold)? - self.squared(sold - token_in)?;
+        let amount_out = delta / self.denominator()?;
+        u64::try_from(amount_out).map_err(|_| MathError::Overflow)
+    }
+
+    fn tokens_in_for_sol(&self, sold: u64, sol_out: u64) -> Result<u64, MathError> {
+        let x1_squared = self
+            .denominator()?
+            .checked_mul(sol_out as u128)
+            .and_then(|delta| self.squared(sold).ok()?.checked_sub(delta))
+            .ok_or(MathError::NotEnoughSolInVault)?;
+
+        let amount_in = self.position(sold)? - isqrt(x1_squared);
+        if amount_in > sold as u128 {
+            return Err(MathError::NotEnoughSolInVault);
+        }
+        Ok(amount_in as u64)
+    }
+
+    // d(cost)/ds = 2x / D
+    fn spot_price(&self, sold: u64, token_unit: u64) -> Result<u128, MathError> {
+        mul_div_floor(
+            2 * self.position(sold)?,
+            token_unit as u128,
+            self.denominator()?,
+        )
+    }
+}
+
+// The quadratic curve's offset is `V * TOKEN_SCALE / LAMPORTS_PER_SOL` token base units and
+// its denominator `P * 1e21`.
+pub struct QuadraticCurve {
+    pub total_supply: u64,
+    pub virtual_sol: u64,
+    pub proportion: u64,
+}
+
+impl SquareLaw for QuadraticCurve {
+    fn supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    fn offset(&self) -> Result<u128, MathError> {
+        (self.virtual_sol as u128)
+            .checked_mul(TOKEN_SCALE / LAMPORTS_PER_SOL)
+            .ok_or(MathError::Overflow)
+    }
+
+    fn denominator(&self) -> Result<u128, MathError> {
+        (self.proportion as u128)
+            .checked_mul(TOKEN_SCALE * TOKEN_SCALE / LAMPORTS_PER_SOL)
+            .ok_or(MathError::Overflow)
+    }
+}
+
+// `x * y = k` with x = V + cost and y = T * P / 1000 - sold.
+pub struct ConstantProductCurve {
+    pub total_supply: u64,
+    pub virtual_sol: u64,
+    pub proportion: u64,
+}
+
+impl ConstantProductCurve {
+    fn virtual_tokens(&self) -> Result<u128, MathError> {
+        mul_div_floor(
+            self.total_supply as u128,
+            self.proportion as u128,
+            PROPORTION_DENOMINATOR as u128,
+        )
+    }
+
+    fn token_reserve(&self, sold: u64) -> Result<u128, MathError> {
+        self.virtual_tokens()?
+            .checked_sub(sold as u128)
+            .filter(|reserve| *reserve > 0)
+            .ok_or(MathError::NotEnoughTokenInVault)
+    }
+
+    // Largest `sold` whose `cost_ceil` fits in `budget`. The sol reserve there is
+    // `ceil(V * Vt / (Vt - sold)) <= budget + V`, so the token reserve is at least
+    // `V * Vt / (budget + V)`.
+    fn max_sold_within(&self, budget: u128) -> Result<u128, MathError> {
+        let virtual_tokens = self.virtual_tokens()?;
+        let sol_reserve = budget
+            .checked_add(self.virtual_sol as u128)
+            .ok_or(MathError::Overflow)?;
+        let token_reserve = mul_div_ceil(self.virtual_sol as u128, virtual_tokens, sol_reserve)?;
+        Ok(virtual_tokens.saturating_sub(token_reserve))
+    }
+}
+
+impl BondingCurve for ConstantProductCurve {
+    fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    fn cost(&self, sold: u64) -> Result<u128, MathError> {
+        let sol_reserve = mul_div_floor(self.virtual_sol as u128, self.virtual_tokens()?, self.token_reserve(sold)?)?;
+        Ok(sol_reserve - self.virtual_sol as u128)
+    }
+
+    fn cost_ceil(&self, sold: u64) -> Result<u128, MathError> {
+        let sol_reserve = mul_div_ceil(self.virtual_sol as u128, self.virtual_tokens()?, self.token_reserve(sold)?)?;
+        Ok(sol_reserve - self.virtual_sol as u128)
+    }
+
+    fn tokens_out_for_sol(&self, sold: u64, sol_in: u64) -> Result<u64, MathError> {
+        let budget = self
+            .cost(sold)?
+            .checked_add(sol_in as u128)
+            .ok_or(MathError::Overflow)?;
+
+        let amount_out = self.max_sold_within(budget)?.saturating_sub(sold as u128);
+        let amount_out = u64::try_from(amount_out).map_err(|_| MathError::Overflow)?;
+        within_supply(self, sold, sol_in, amount_out)
+    }
+
+    fn tokens_in_for_sol(&self, sold: u64, sol_out: u64) -> Result<u64, MathError> {
+        if sol_out == 0 {
+            return Ok(0);
+        }
+        let budget = self
+            .cost(sold)?
+            .checked_sub(sol_out as u128)
+            .ok_or(MathError::NotEnoughSolInVault)?;
+
+        let remaining = self.max_sold_within(budget)?.min(sold as u128);
+        Ok(sold - remaining as u64)
+    }
+
+    // d(cost)/ds = V * Vt / (Vt - s)^2
+    fn spot_price(&self, sold: u64, token_unit: u64) -> Result<u128, MathError> {
+        let token_reserve = self.token_reserve(sold)?;
+        let unit_value = (self.virtual_sol as u128)
+            .checked_mul(token_unit as u128)
+            .ok_or(MathError::Overflow)?;
+        Ok(mul_div_floor(unit_value, self.virtual_tokens()?, token_reserve)? / token_reserve)
+    }
+}
+
+// cost(s) = V * s / T + V * P * s^2 / (2000 * T^2), which completes to a square with offset
+// `1000 * T / P` and denominator `2000 * T^2 / (V * P)`.
+pub struct LinearCurve {
+    pub total_supply: u64,
+    pub virtual_sol: u64,
+    pub proportion: u64,
+}
+
+impl SquareLaw for LinearCurve {
+    fn supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    fn offset(&self) -> Result<u128, MathError> {
+        mul_div_floor(
+            self.total_supply as u128,
+            PROPORTION_DENOMINATOR as u128,
+            self.proportion as u128,
+        )
+    }
+
+    fn denominator(&self) -> Result<u128, MathError> {
+        let ramp = (self.virtual_sol as u128)
+            .checked_mul(self.proportion as u128)
+            .ok_or(MathError::Overflow)?;
+        let denominator = mul_div_floor(
+            2 * PROPORTION_DENOMINATOR as u128 * self.total_supply as u128,
+            self.total_supply as u128,
+            ramp,
+        )?;
+        if denominator == 0 {
+            return Err(MathError::InvalidCurveParameters);
+        }
+        Ok(denominator)
+    }
+}
+
+// cost(s) = V * 1000 / P * (e^(P * s / (1000 * T)) - 1)
+pub struct ExponentialCurve {
+    pub total_supply: u64,
+    pub virtual_sol: u64,
+    pub proportion: u64,
+}
+
+impl ExponentialCurve {
+    // P * s / (1000 * T) in EXP_ONE fixed point
+    fn exponent(&self, sold: u64) -> Result<u128, MathError> {
+        mul_div_floor(
+            (sold as u128) * self.proportion as u128,
+            EXP_ONE,
+            PROPORTION_DENOMINATOR as u128 * self.total_supply as u128,
+        )
+    }
+
+    fn exponent_ceil(&self, sold: u64) -> Result<u128, MathError> {
+        mul_div_ceil(
+            (sold as u128) * self.proportion as u128,
+            EXP_ONE,
+            PROPORTION_DENOMINATOR as u128 * self.total_supply as u128,
+        )
+    }
+}
+
+impl BondingCurve for ExponentialCurve {
+    fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    fn cost(&self, sold: u64) -> Result<u128, MathError> {
+        let growth = exp_fixed(self.exponent(sold)?)? - EXP_ONE;
+
+        mul_div_floor(
+            growth,
+            self.virtual_sol as u128 * PROPORTION_DENOMINATOR as u128,
+            self.proportion as u128 * EXP_ONE,
+        )
+    }
+
+    fn cost_ceil(&self, sold: u64) -> Result<u128, MathError> {
+        let growth = exp_fixed(self.exponent_ceil(sold)?)? - EXP_ONE;
+
+        mul_div_ceil(
+            growth,
+            self.virtual_sol as u128 * PROPORTION_DENOMINATOR as u128,
+            self.proportion as u128 * EXP_ONE,
+        )
+    }
+
+    // d(cost)/ds = V / T * e^(P * s / (1000 * T))
+    fn spot_price(&self, sold: u64, token_unit: u64) -> Result<u128, MathError> {
+        let scaled = mul_div_floor(exp_fixed(self.exponent(sold)?)?, self.virtual_sol as u128, EXP_ONE)?;
+        mul_div_floor(scaled, token_unit as u128, self.total_supply as u128)
+    }
+}
@@ -0,0 +1,27 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    Overflow,
+    InvalidAmount,
+    InvalidFee,
+    InvalidCurveParameters,
+    NotEnoughTokenInVault,
+    NotEnoughSolInVault,
+    TokenAmountToSellTooBig,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            MathError::Overflow => "Overflow or underflow occured",
+            MathError::InvalidAmount => "Invalid amount to swap",
+            MathError::InvalidFee => "Invalid fee. It must be between 0 and 10000 basis points.",
+            MathError::InvalidCurveParameters => "Curve parameters are invalid",
+            MathError::NotEnoughTokenInVault => "Token is not enough in vault",
+            MathError::NotEnoughSolInVault => "SOL is not enough in vault",
+            MathError::TokenAmountToSellTooBig => "Token amount is too big to sell",
+        };
+        f.write_str(message)
+    }
+}
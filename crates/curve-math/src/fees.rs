@@ -0,0 +1,96 @@
+use crate::error::MathError;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// A trade fee: `bps` of the amount, but never less than `min_lamports`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeRate {
+    pub bps: u16,
+    pub min_lamports: u64,
+}
+
+impl FeeRate {
+    pub fn validate(&self) -> Result<(), MathError> {
+        if self.bps as u64 > BPS_DENOMINATOR {
+            return Err(MathError::InvalidFee);
+        }
+        Ok(())
+    }
+}
+
+// `bps` of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64, MathError> {
+    if bps as u64 > BPS_DENOMINATOR {
+        return Err(MathError::InvalidFee);
+    }
+    Ok((amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64)
+}
+
+// `bps` of `amount`, rounded up.
+pub fn bps_of_ceil(amount: u64, bps: u16) -> Result<u64, MathError> {
+    if bps as u64 > BPS_DENOMINATOR {
+        return Err(MathError::InvalidFee);
+    }
+    Ok((amount as u128 * bps as u128).div_ceil(BPS_DENOMINATOR as u128) as u64)
+}
+
+// Splits `amount` into the fee and what is left after it. The bps part rounds up, so a trade
+// never pays less than its rate, and the fee is capped at `amount`. Trades that would be left
+// with nothing are rejected by the quotes in `PoolState`.
+pub fn split_fee(amount: u64, fee: FeeRate) -> Result<(u64, u64), MathError> {
+    let proportional = bps_of_ceil(amount, fee.bps)?;
+    let fee = proportional.max(fee.min_lamports).min(amount);
+    Ok((fee, amount - fee))
+}
+
+// Smallest amount that still leaves `net` once the fee is taken out of it.
+pub fn gross_up_for_fee(net: u64, fee: FeeRate) -> Result<u64, MathError> {
+    fee.validate()?;
+    if fee.bps as u64 == BPS_DENOMINATOR {
+        return Err(MathError::InvalidFee);
+    }
+    if net == 0 {
+        return Ok(0);
+    }
+
+    // `g - ceil(g * bps / 10000) >= net` holds exactly when `g * (10000 - bps) >= net * 10000`
+    let keep = (BPS_DENOMINATOR - fee.bps as u64) as u128;
+    let proportional = (net as u128 * BPS_DENOMINATOR as u128).div_ceil(keep);
+    let minimum = net as u128 + fee.min_lamports as u128;
+
+    u64::try_from(proportional.max(minimum)).map_err(|_| MathError::Overflow)
+}
+
+// Launch fees decay exponentially by halving the excess over the base fee this many times
+// across the launch window.
+pub const LAUNCH_FEE_HALVINGS: u128 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchDecay {
+    Linear,
+    Exponential,
+}
+
+// Fee in bps `elapsed` slots or seconds into a launch window of `duration`. It starts at
+// `start_bps` and falls to `base_bps` by the end of the window; a start at or below the base
+// fee means there is no launch phase.
+pub fn launch_fee_bps(
+    base_bps: u16,
+    start_bps: u16,
+    elapsed: u64,
+    duration: u64,
+    decay: LaunchDecay,
+) -> u16 {
+    if start_bps <= base_bps || elapsed >= duration {
+        return base_bps;
+    }
+
+    let excess = (start_bps - base_bps) as u128;
+    let remaining = match decay {
+        LaunchDecay::Linear => excess * (duration - elapsed) as u128 / duration as u128,
+        LaunchDecay::Exponential => {
+            excess >> (elapsed as u128 * LAUNCH_FEE_HALVINGS / duration as u128)
+        }
+    };
+    base_bps + remaining as u16
+}
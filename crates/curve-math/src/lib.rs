@@ -0,0 +1,22 @@
+//! Bonding-curve, AMM, fee and price maths for the `ai_agent` program.
+//!
+//! The program prices every trade through this crate, so off-chain services that quote
+//! with it (natively, or from TypeScript through `curve-math-wasm`) get the same result
+//! to the lamport.
+#![no_std]
+
+extern crate alloc;
+
+pub mod amm;
+pub mod curve;
+pub mod error;
+pub mod fees;
+pub mod math;
+pub mod pool;
+
+pub use amm::*;
+pub use curve::*;
+pub use error::MathError;
+pub use fees::*;
+pub use math::*;
+pub use pool::*;
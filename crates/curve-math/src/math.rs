@@ -0,0 +1,76 @@
+use crate::error::MathError;
+
+// Token base units per curve unit: the quadratic curve is expressed in units of 1e15 base tokens.
+pub const TOKEN_SCALE: u128 = 1_000_000_000_000_000;
+pub const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+
+// Fixed-point precision of the exponential curve. MAX_EXPONENT keeps e^x * EXP_ONE and the
+// squaring steps of `exp_fixed` inside a u128.
+pub const EXP_ONE: u128 = 1_000_000_000_000;
+pub const MAX_EXPONENT: u128 = 32;
+pub const EXP_HALVINGS: u32 = 6;
+pub const EXP_TAYLOR_TERMS: u128 = 12;
+
+// Curve `proportion` values for the constant-product, linear and exponential curves are per-mille.
+pub const PROPORTION_DENOMINATOR: u64 = 1_000;
+
+// Integer square root, rounded down.
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    // Start from a power of two that is never below the root so Newton's
+    // iteration decreases monotonically onto floor(sqrt(value)).
+    let bits = 128 - value.leading_zeros();
+    let mut x = 1_u128 << bits.div_ceil(2);
+    loop {
+        let y = (x + value / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+// `a * b / d` rounded down, without overflowing as long as `(a % d) * b` fits in a u128.
+pub fn mul_div_floor(a: u128, b: u128, d: u128) -> Result<u128, MathError> {
+    if d == 0 {
+        return Err(MathError::Overflow);
+    }
+    (a / d)
+        .checked_mul(b)
+        .and_then(|whole| whole.checked_add((a % d).checked_mul(b)? / d))
+        .ok_or(MathError::Overflow)
+}
+
+// `a * b / d` rounded up, under the same bound as `mul_div_floor`.
+pub fn mul_div_ceil(a: u128, b: u128, d: u128) -> Result<u128, MathError> {
+    let floor = mul_div_floor(a, b, d)?;
+    let exact = (a % d).checked_mul(b).ok_or(MathError::Overflow)? % d == 0;
+    if exact {
+        return Ok(floor);
+    }
+    floor.checked_add(1).ok_or(MathError::Overflow)
+}
+
+// e^x in EXP_ONE fixed point, for 0 <= x <= MAX_EXPONENT. The argument is scaled down by
+// 2^EXP_HALVINGS, expanded as a Taylor series and squared back up. Every step rounds down
+// and is monotonic in `x`, so the result never decreases as `x` grows.
+pub fn exp_fixed(x: u128) -> Result<u128, MathError> {
+    if x > MAX_EXPONENT * EXP_ONE {
+        return Err(MathError::Overflow);
+    }
+
+    let reduced = x >> EXP_HALVINGS;
+    let mut term = EXP_ONE;
+    let mut sum = EXP_ONE;
+    for i in 1..=EXP_TAYLOR_TERMS {
+        term = term * reduced / (i * EXP_ONE);
+        sum += term;
+    }
+    for _ in 0..EXP_HALVINGS {
+        sum = sum * sum / EXP_ONE;
+    }
+
+    Ok(sum)
+}
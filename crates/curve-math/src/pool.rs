@@ -0,0 +1,173 @@
+use alloc::boxed::Box;
+
+use crate::curve::{BondingCurve, CurveKind};
+use crate::error::MathError;
+use crate::fees::{gross_up_for_fee, split_fee, FeeRate};
+
+// The parts of a liquidity pool that determine its prices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolState {
+    pub curve_kind: CurveKind,
+    pub total_supply: u64,
+    pub reserve_token: u64,
+    pub reserve_sol: u64,
+    pub virtual_sol_reserves: u64,
+    pub proportion: u64,
+}
+
+// Spot prices are lamports per whole token in fixed point with nine decimals. Whole lamports are
+// too coarse: a fresh pool's tokens cost a few dozen lamports each.
+pub const PRICE_SCALE: u64 = 1_000_000_000;
+
+// Base units whose price in lamports is one whole token's price times PRICE_SCALE
+pub(crate) fn scaled_token_unit(decimals: u8) -> Result<u64, MathError> {
+    10_u64
+        .checked_pow(decimals as u32)
+        .and_then(|unit| unit.checked_mul(PRICE_SCALE))
+        .ok_or(MathError::Overflow)
+}
+
+// Amounts settled by a single trade. `sol_amount` is what the user pays on a buy
+// (fee included) or receives on a sell (fee already taken out).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TradeQuote {
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub fee: u64,
+}
+
+impl PoolState {
+    pub fn curve(&self) -> Box<dyn BondingCurve> {
+        self.curve_kind
+            .curve(self.total_supply, self.virtual_sol_reserves, self.proportion)
+    }
+
+    pub fn sold(&self) -> Result<u64, MathError> {
+        self.total_supply
+            .checked_sub(self.reserve_token)
+            .ok_or(MathError::Overflow)
+    }
+
+    pub fn quote_buy(&self, amount: u64, fee_rate: FeeRate) -> Result<TradeQuote, MathError> {
+        if amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        // A minimum fee at or above the amount would take all of it and buy nothing
+        let (fee, sol_in) = split_fee(amount, fee_rate)?;
+        if sol_in == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        let token_amount = self.curve().tokens_out_for_sol(self.sold()?, sol_in)?;
+
+        if token_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        if token_amount > self.reserve_token {
+            return Err(MathError::NotEnoughTokenInVault);
+        }
+
+        Ok(TradeQuote { sol_amount: amount, token_amount, fee })
+    }
+
+    pub fn quote_buy_exact_out(&self, token_amount: u64, fee_rate: FeeRate) -> Result<TradeQuote, MathError> {
+        if token_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        if token_amount > self.reserve_token {
+            return Err(MathError::NotEnoughTokenInVault);
+        }
+
+        let sol_in = self.curve().sol_in_for_tokens(self.sold()?, token_amount)?;
+        let amount = gross_up_for_fee(sol_in, fee_rate)?;
+        let (fee, _) = split_fee(amount, fee_rate)?;
+
+        Ok(TradeQuote { sol_amount: amount, token_amount, fee })
+    }
+
+    pub fn quote_sell(&self, amount: u64, fee_rate: FeeRate) -> Result<TradeQuote, MathError> {
+        if amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        let sol_out = self.curve().sol_out_for_tokens(self.sold()?, amount)?;
+        let (fee, sol_amount) = split_fee(sol_out, fee_rate)?;
+        if sol_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+
+        if self.reserve_sol < sol_out {
+            return Err(MathError::NotEnoughSolInVault);
+        }
+
+        Ok(TradeQuote { sol_amount, token_amount: amount, fee })
+    }
+
+    pub fn quote_sell_exact_out(&self, sol_amount: u64, fee_rate: FeeRate) -> Result<TradeQuote, MathError> {
+        if sol_amount == 0 {
+            return Err(MathError::InvalidAmount);
+        }
+        if self.reserve_sol < sol_amount {
+            return Err(MathError::NotEnoughSolInVault);
+        }
+
+        let sol_out = gross_up_for_fee(sol_amount, fee_rate)?;
+        if self.reserve_sol < sol_out {
+            return Err(MathError::NotEnoughSolInVault);
+        }
+        let token_amount = self.curve().tokens_in_for_sol(self.sold()?, sol_out)?;
+        let (fee, _) = split_fee(sol_out, fee_rate)?;
+
+        Ok(TradeQuote { sol_amount, token_amount, fee })
+    }
+
+    pub fn apply_buy(&mut self, quote: &TradeQuote) -> Result<(), MathError> {
+        let sol_into_pool = quote.sol_amount - quote.fee;
+        let reserve_sol = self
+            .reserve_sol
+            .checked_add(sol_into_pool)
+            .ok_or(MathError::Overflow)?;
+        let reserve_token = self
+            .reserve_token
+            .checked_sub(quote.token_amount)
+            .ok_or(MathError::NotEnoughTokenInVault)?;
+
+        self.reserve_sol = reserve_sol;
+        self.reserve_token = reserve_token;
+        Ok(())
+    }
+
+    // The fee on a sell is paid out of the vault too, so it leaves the reserve with the proceeds
+    pub fn apply_sell(&mut self, quote: &TradeQuote) -> Result<(), MathError> {
+        let sol_out_of_pool = quote
+            .sol_amount
+            .checked_add(quote.fee)
+            .ok_or(MathError::Overflow)?;
+        let reserve_sol = self
+            .reserve_sol
+            .checked_sub(sol_out_of_pool)
+            .ok_or(MathError::NotEnoughSolInVault)?;
+        let reserve_token = self
+            .reserve_token
+            .checked_add(quote.token_amount)
+            .ok_or(MathError::Overflow)?;
+
+        self.reserve_sol = reserve_sol;
+        self.reserve_token = reserve_token;
+        Ok(())
+    }
+
+    // Marginal price of one whole token (10^decimals base units) in lamports, times PRICE_SCALE
+    pub fn spot_price(&self, decimals: u8) -> Result<u64, MathError> {
+        let price = self.curve().spot_price(self.sold()?, scaled_token_unit(decimals)?)?;
+        u64::try_from(price).map_err(|_| MathError::Overflow)
+    }
+
+    // Fully diluted market cap in lamports: the spot price of one whole token times the
+    // whole-token supply, `total_supply / 10^decimals`. Pricing all `total_supply` base units
+    // at once gives the same value without rounding the price first.
+    pub fn market_cap(&self) -> Result<u64, MathError> {
+        let market_cap = self.curve().spot_price(self.sold()?, self.total_supply)?;
+        u64::try_from(market_cap).map_err(|_| MathError::Overflow)
+    }
+}
@@ -0,0 +1,107 @@
+use curve_math::{AmmState, CurveKind, FeeRate, MathError, PoolState};
+
+#[test]
+fn swaps_never_shrink_the_product() {
+    for fee_bps in [0, 25, 100] {
+        let mut amm = AmmState {
+            reserve_token: 200_000_000_000_000_000,
+            reserve_sol: 85_000_000_000,
+            lp_supply: 0,
+            fee_bps,
+        };
+        amm.lp_supply = AmmState::initial_shares(amm.reserve_token, amm.reserve_sol).unwrap();
+
+        for amount in [1, 1_000, 5_000_000_000, 40_000_000_000] {
+            let k = amm.reserve_token as u128 * amm.reserve_sol as u128;
+            let tokens_out = amm.quote_buy(amount).unwrap();
+            amm.reserve_sol += amount;
+            amm.reserve_token -= tokens_out;
+            assert!(amm.reserve_token as u128 * amm.reserve_sol as u128 >= k, "buy of {amount} at {fee_bps}");
+            if tokens_out == 0 {
+                // The fee ate the whole of a dust buy.
+                continue;
+            }
+
+            let k = amm.reserve_token as u128 * amm.reserve_sol as u128;
+            let sol_out = amm.quote_sell(tokens_out).unwrap();
+            amm.reserve_token += tokens_out;
+            amm.reserve_sol -= sol_out;
+            assert!(sol_out <= amount, "round trip of {amount} at {fee_bps} made a profit");
+            assert!(amm.reserve_token as u128 * amm.reserve_sol as u128 >= k, "sell of {amount} at {fee_bps}");
+        }
+    }
+}
+
+#[test]
+fn deposits_and_withdrawals_never_dilute_other_providers() {
+    let mut amm = AmmState {
+        reserve_token: 206_900_000_000_000_000,
+        reserve_sol: 85_000_000_007,
+        lp_supply: 0,
+        fee_bps: 25,
+    };
+    amm.lp_supply = AmmState::initial_shares(amm.reserve_token, amm.reserve_sol).unwrap();
+
+    for sol_in in [1_000, 3_333_333_333, 50_000_000_000] {
+        let sol_per_share = amm.reserve_sol as u128 * 1_000_000_000 / amm.lp_supply as u128;
+        let token_per_share = amm.reserve_token as u128 * 1_000_000_000 / amm.lp_supply as u128;
+
+        let (token_in, shares) = amm.quote_deposit(sol_in).unwrap();
+        amm.reserve_sol += sol_in;
+        amm.reserve_token += token_in;
+        amm.lp_supply += shares;
+        assert!(amm.reserve_sol as u128 * 1_000_000_000 / amm.lp_supply as u128 >= sol_per_share);
+        assert!(amm.reserve_token as u128 * 1_000_000_000 / amm.lp_supply as u128 >= token_per_share);
+
+        let (token_out, sol_out) = amm.quote_withdraw(shares).unwrap();
+        assert!(token_out <= token_in && sol_out <= sol_in, "deposit of {sol_in} withdrew more than it put in");
+        amm.reserve_sol -= sol_out;
+        amm.reserve_token -= token_out;
+        amm.lp_supply -= shares;
+    }
+}
+
+#[test]
+fn spot_price_rejects_oversized_decimals() {
+    let amm = AmmState { reserve_token: 200_000_000_000_000_000, reserve_sol: 85_000_000_000, lp_supply: 1, fee_bps: 25 };
+    assert_eq!(amm.spot_price(9).unwrap(), 425_000_000_000);
+    assert_eq!(amm.spot_price(u8::MAX), Err(MathError::Overflow));
+}
+
+#[test]
+fn graduation_opens_the_pool_at_the_curves_last_price() {
+    let total_supply = 1_000_000_000_000_000_000;
+    for (curve_kind, proportion) in [
+        (CurveKind::Quadratic, 1_280),
+        (CurveKind::ConstantProduct, 1_073),
+        (CurveKind::Linear, 50_000),
+        (CurveKind::Exponential, 12_000),
+    ] {
+        let mut pool = PoolState {
+            curve_kind,
+            total_supply,
+            reserve_token: total_supply,
+            reserve_sol: 0,
+            virtual_sol_reserves: 30_000_000_000,
+            proportion,
+        };
+        let buy = pool.quote_buy(85_000_000_000, FeeRate { bps: 0, min_lamports: 0 }).unwrap();
+        pool.apply_buy(&buy).unwrap();
+        let price = pool.spot_price(9).unwrap();
+
+        let amm = AmmState::opening(pool.reserve_sol, pool.reserve_token, price, 9, 25).unwrap();
+        assert!(amm.reserve_token <= pool.reserve_token);
+        let amm_price = amm.spot_price(9).unwrap();
+        assert!(amm_price >= price, "{curve_kind:?} opened below the curve");
+        assert!((amm_price - price) * 1_000 <= price, "{curve_kind:?} opened at {amm_price}, curve at {price}");
+
+        // Depositing every unsold token instead would crash the price
+        let all_in = AmmState { reserve_token: pool.reserve_token, ..amm };
+        assert!(all_in.spot_price(9).unwrap() < price, "{curve_kind:?}");
+    }
+}
+
+#[test]
+fn graduation_needs_a_price() {
+    assert_eq!(AmmState::opening(85_000_000_000, 1_000, 0, 9, 25), Err(MathError::InvalidAmount));
+}
@@ -0,0 +1,168 @@
+use curve_math::{BondingCurve, CurveKind};
+
+const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000;
+
+fn curves() -> Vec<(CurveKind, Box<dyn BondingCurve>)> {
+    [
+        (CurveKind::Quadratic, 25_000_000_000, 1_280),
+        (CurveKind::ConstantProduct, 30_000_000_000, 1_073),
+        (CurveKind::Linear, 30_000_000_000, 50_000),
+        (CurveKind::Exponential, 30_000_000_000, 12_000),
+    ]
+    .into_iter()
+    .map(|(kind, virtual_sol, proportion)| {
+        kind.validate(proportion).unwrap();
+        (kind, kind.curve(TOTAL_SUPPLY, virtual_sol, proportion))
+    })
+    .collect()
+}
+
+// Deterministic spread of positions and trade sizes across the whole supply.
+fn samples(seed: u64, count: usize, max: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 1) % max.max(1)
+        })
+        .collect()
+}
+
+#[test]
+fn cost_starts_at_zero_and_never_decreases() {
+    for (kind, curve) in curves() {
+        assert_eq!(curve.cost(0).unwrap(), 0, "{kind:?}");
+
+        let mut positions = samples(1, 200, TOTAL_SUPPLY);
+        positions.sort_unstable();
+        let mut previous = 0;
+        for sold in positions {
+            let cost = curve.cost(sold).unwrap();
+            assert!(cost >= previous, "{kind:?} decreased at {sold}");
+            previous = cost;
+        }
+    }
+}
+
+#[test]
+fn buying_then_selling_never_returns_more_sol() {
+    for (kind, curve) in curves() {
+        for (sold, sol_in) in samples(2, 50, TOTAL_SUPPLY / 2)
+            .into_iter()
+            .zip(samples(3, 50, 100_000_000_000))
+        {
+            let tokens = curve.tokens_out_for_sol(sold, sol_in).unwrap();
+            let sol_back = curve.sol_out_for_tokens(sold + tokens, tokens).unwrap();
+            assert!(sol_back <= sol_in, "{kind:?} round trip paid {sol_back} for {sol_in}");
+        }
+    }
+}
+
+#[test]
+fn splitting_a_buy_never_yields_more_tokens() {
+    for (kind, curve) in curves() {
+        for (sold, sol_in) in samples(4, 50, TOTAL_SUPPLY / 2)
+            .into_iter()
+            .zip(samples(5, 50, 100_000_000_000))
+        {
+            let first = curve.tokens_out_for_sol(sold, sol_in / 3).unwrap();
+            let second = curve
+                .tokens_out_for_sol(sold + first, sol_in - sol_in / 3)
+                .unwrap();
+            let whole = curve.tokens_out_for_sol(sold, sol_in).unwrap();
+            assert!(first + second <= whole, "{kind:?} split buy beat a single buy");
+        }
+    }
+}
+
+#[test]
+fn exact_out_inverses_cover_the_requested_amount() {
+    for (kind, curve) in curves() {
+        for (sold, amount) in samples(6, 50, TOTAL_SUPPLY / 2)
+            .into_iter()
+            .zip(samples(7, 50, TOTAL_SUPPLY / 100))
+        {
+            let sol_in = curve.sol_in_for_tokens(sold, amount).unwrap();
+            let tokens = curve.tokens_out_for_sol(sold, sol_in).unwrap();
+            assert!(tokens >= amount, "{kind:?} exact-in bought {tokens} of {amount}");
+
+            let sol_out = curve.sol_out_for_tokens(sold, amount.min(sold)).unwrap();
+            let tokens_in = curve.tokens_in_for_sol(sold, sol_out).unwrap();
+            assert!(tokens_in <= amount.min(sold), "{kind:?} exact-out sold too much");
+            assert!(
+                curve.sol_out_for_tokens(sold, tokens_in).unwrap() >= sol_out,
+                "{kind:?} exact-out paid short"
+            );
+        }
+    }
+}
+
+#[test]
+fn exact_out_buys_always_cost_something() {
+    for (kind, curve) in curves() {
+        for sold in samples(10, 50, TOTAL_SUPPLY / 2) {
+            // A single base unit is worth far less than a lamport, so charging the difference
+            // of two rounded-down costs would hand it out for free.
+            assert!(curve.sol_in_for_tokens(sold, 1).unwrap() >= 1, "{kind:?} gave a token away at {sold}");
+        }
+    }
+}
+
+#[test]
+fn buys_pay_out_the_largest_affordable_amount() {
+    for (kind, curve) in curves() {
+        for (sold, sol_in) in samples(11, 50, TOTAL_SUPPLY / 2)
+            .into_iter()
+            .zip(samples(12, 50, 100_000_000_000))
+        {
+            let tokens = curve.tokens_out_for_sol(sold, sol_in).unwrap();
+            assert!(curve.sol_in_for_tokens(sold, tokens).unwrap() <= sol_in, "{kind:?} overpaid {tokens}");
+            assert!(
+                curve.sol_in_for_tokens(sold, tokens + 1).unwrap() > sol_in,
+                "{kind:?} underpaid {tokens} for {sol_in}"
+            );
+        }
+    }
+}
+
+#[test]
+fn spot_price_sits_between_neighbouring_trades() {
+    for (kind, curve) in curves() {
+        for (sold, amount) in samples(8, 50, TOTAL_SUPPLY / 2)
+            .into_iter()
+            .zip(samples(9, 50, TOTAL_SUPPLY / 1_000))
+        {
+            let amount = amount.clamp(1, sold.max(1));
+            let spot = curve.spot_price(sold, amount).unwrap();
+            // Every curve is convex, so buying costs at least the spot rate and selling
+            // pays at most it, up to rounding.
+            let tolerance = spot / 1_000_000 + 2;
+            let sol_in = curve.sol_in_for_tokens(sold, amount).unwrap() as u128;
+            assert!(spot <= sol_in + tolerance, "{kind:?} spot {spot} above buy cost {sol_in}");
+            if amount <= sold {
+                let sol_out = curve.sol_out_for_tokens(sold, amount).unwrap() as u128;
+                assert!(spot + tolerance >= sol_out, "{kind:?} spot {spot} below sell value {sol_out}");
+            }
+        }
+    }
+}
+
+#[test]
+fn buys_never_exceed_the_remaining_supply() {
+    for (kind, curve) in curves() {
+        let sold = TOTAL_SUPPLY - TOTAL_SUPPLY / 1_000;
+        let tokens = curve.tokens_out_for_sol(sold, u64::MAX / 4).unwrap_or(0);
+        assert!(tokens <= TOTAL_SUPPLY - sold, "{kind:?} oversold the pool");
+        assert!(curve.sol_in_for_tokens(sold, TOTAL_SUPPLY - sold + 1).is_err());
+    }
+}
+
+#[test]
+fn invalid_shapes_are_rejected() {
+    assert!(CurveKind::Quadratic.validate(0).is_err());
+    assert!(CurveKind::ConstantProduct.validate(1_000).is_err());
+    assert!(CurveKind::Linear.validate(0).is_err());
+    assert!(CurveKind::Exponential.validate(32_001).is_err());
+}
@@ -0,0 +1,51 @@
+use curve_math::{gross_up_for_fee, launch_fee_bps, split_fee, FeeRate, LaunchDecay};
+
+#[test]
+fn gross_up_is_the_smallest_amount_covering_net() {
+    for bps in [0, 1, 25, 100, 250, 9_999] {
+        for min_lamports in [0, 5_000] {
+            let fee = FeeRate { bps, min_lamports };
+            for net in [0, 1, 2, 999, 10_000, 1_000_000_007, 40_000_000_000] {
+                let gross = gross_up_for_fee(net, fee).unwrap();
+                assert!(split_fee(gross, fee).unwrap().1 >= net, "{fee:?} short at {net}");
+                if gross > 0 {
+                    assert!(split_fee(gross - 1, fee).unwrap().1 < net, "{fee:?} not minimal at {net}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn proportional_fees_round_up() {
+    let fee = FeeRate { bps: 100, min_lamports: 0 };
+    assert_eq!(split_fee(10_000, fee).unwrap(), (100, 9_900));
+    assert_eq!(split_fee(10_001, fee).unwrap(), (101, 9_900));
+    assert_eq!(split_fee(1, fee).unwrap(), (1, 0));
+    assert_eq!(split_fee(0, fee).unwrap(), (0, 0));
+    assert_eq!(split_fee(1, FeeRate { bps: 0, min_lamports: 0 }).unwrap(), (0, 1));
+}
+
+#[test]
+fn fees_above_the_whole_amount_are_rejected() {
+    let fee = FeeRate { bps: 10_001, min_lamports: 0 };
+    assert!(split_fee(1_000, fee).is_err());
+    assert!(gross_up_for_fee(1_000, FeeRate { bps: 10_000, min_lamports: 0 }).is_err());
+}
+
+#[test]
+fn launch_fee_decays_to_the_base_fee() {
+    for decay in [LaunchDecay::Linear, LaunchDecay::Exponential] {
+        let duration = 150;
+        assert_eq!(launch_fee_bps(100, 5_000, 0, duration, decay), 5_000);
+        assert_eq!(launch_fee_bps(100, 5_000, duration, duration, decay), 100);
+        assert_eq!(launch_fee_bps(100, 50, 0, duration, decay), 100);
+
+        let mut previous = u16::MAX;
+        for elapsed in 0..=duration + 10 {
+            let fee = launch_fee_bps(100, 5_000, elapsed, duration, decay);
+            assert!(fee <= previous && fee >= 100, "{decay:?} rose at {elapsed}");
+            previous = fee;
+        }
+    }
+}
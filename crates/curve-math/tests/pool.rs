@@ -0,0 +1,110 @@
+use curve_math::{CurveKind, FeeRate, MathError, PoolState, TradeQuote, PRICE_SCALE};
+
+const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000;
+
+fn pool(curve_kind: CurveKind, proportion: u64) -> PoolState {
+    PoolState {
+        curve_kind,
+        total_supply: TOTAL_SUPPLY,
+        reserve_token: TOTAL_SUPPLY,
+        reserve_sol: 0,
+        virtual_sol_reserves: 30_000_000_000,
+        proportion,
+    }
+}
+
+fn pools() -> [PoolState; 4] {
+    [
+        pool(CurveKind::Quadratic, 1_280),
+        pool(CurveKind::ConstantProduct, 1_073),
+        pool(CurveKind::Linear, 50_000),
+        pool(CurveKind::Exponential, 12_000),
+    ]
+}
+
+#[test]
+fn buys_and_sells_charge_the_fee_rounded_up() {
+    let fee_rate = FeeRate { bps: 100, min_lamports: 0 };
+    for mut pool in pools() {
+        let buy = pool.quote_buy(1_000_000_001, fee_rate).unwrap();
+        assert_eq!(buy.fee, 10_000_001, "{:?}", pool.curve_kind);
+        pool.apply_buy(&buy).unwrap();
+        assert_eq!(pool.reserve_sol, 990_000_000);
+        assert_eq!(pool.reserve_token, TOTAL_SUPPLY - buy.token_amount);
+
+        let sell = pool.quote_sell(buy.token_amount, fee_rate).unwrap();
+        let sol_out = sell.sol_amount + sell.fee;
+        assert!(sol_out <= pool.reserve_sol, "{:?} sold back for more than it took", pool.curve_kind);
+        assert_eq!(sell.fee, (sol_out as u128 * 100).div_ceil(10_000) as u64);
+        pool.apply_sell(&sell).unwrap();
+        assert_eq!(pool.reserve_token, TOTAL_SUPPLY);
+        assert_eq!(pool.reserve_sol, 990_000_000 - sol_out);
+    }
+}
+
+#[test]
+fn exact_out_quotes_cover_the_curve_and_the_fee() {
+    let fee_rate = FeeRate { bps: 250, min_lamports: 5_000 };
+    for mut pool in pools() {
+        let token_amount = TOTAL_SUPPLY / 100;
+        let buy = pool.quote_buy_exact_out(token_amount, fee_rate).unwrap();
+        let curve_cost = pool.curve().sol_in_for_tokens(0, token_amount).unwrap();
+        assert!(buy.sol_amount - buy.fee >= curve_cost, "{:?} buy under the curve", pool.curve_kind);
+        assert_eq!(buy, TradeQuote { token_amount, ..buy });
+        pool.apply_buy(&buy).unwrap();
+
+        let sell = pool.quote_sell_exact_out(1_000_000, fee_rate).unwrap();
+        assert_eq!(sell.sol_amount, 1_000_000);
+        let curve_value = pool.curve().sol_out_for_tokens(pool.total_supply - pool.reserve_token, sell.token_amount).unwrap();
+        assert!(curve_value >= sell.sol_amount + sell.fee, "{:?} sell paid short", pool.curve_kind);
+    }
+}
+
+#[test]
+fn exact_out_buys_cannot_take_more_than_the_reserve() {
+    let fee_rate = FeeRate { bps: 100, min_lamports: 0 };
+    for mut pool in pools() {
+        pool.reserve_token = TOTAL_SUPPLY / 2;
+        pool.reserve_sol = 50_000_000_000;
+        assert_eq!(
+            pool.quote_buy_exact_out(pool.reserve_token + 1, fee_rate),
+            Err(MathError::NotEnoughTokenInVault),
+            "{:?}",
+            pool.curve_kind
+        );
+        assert_eq!(
+            pool.quote_buy_exact_out(TOTAL_SUPPLY, fee_rate),
+            Err(MathError::NotEnoughTokenInVault),
+            "{:?}",
+            pool.curve_kind
+        );
+    }
+}
+
+#[test]
+fn trades_the_minimum_fee_would_swallow_are_rejected() {
+    let fee_rate = FeeRate { bps: 100, min_lamports: 5_000 };
+    for mut pool in pools() {
+        assert_eq!(pool.quote_buy(5_000, fee_rate), Err(MathError::InvalidAmount), "{:?}", pool.curve_kind);
+        assert_eq!(pool.quote_buy(4_999, fee_rate), Err(MathError::InvalidAmount), "{:?}", pool.curve_kind);
+
+        let buy = pool.quote_buy(1_000_000_000, fee_rate).unwrap();
+        pool.apply_buy(&buy).unwrap();
+        let dust = pool.curve().tokens_in_for_sol(pool.total_supply - pool.reserve_token, 5_000).unwrap() / 2;
+        assert!(dust > 0);
+        assert_eq!(pool.quote_sell(dust, fee_rate), Err(MathError::InvalidAmount), "{:?}", pool.curve_kind);
+    }
+}
+
+#[test]
+fn spot_prices_keep_nine_decimals() {
+    for pool in pools() {
+        let price = pool.spot_price(9).unwrap();
+        let whole_lamports = pool.curve().spot_price(0, 1_000_000_000).unwrap() as u64;
+        assert_eq!(price / PRICE_SCALE, whole_lamports, "{:?}", pool.curve_kind);
+        // The supply is a billion whole tokens, so scaling by a billion prices all of it
+        assert_eq!(price, pool.market_cap().unwrap(), "{:?}", pool.curve_kind);
+        assert_eq!(pool.spot_price(11), Err(MathError::Overflow));
+    }
+    assert_ne!(pools()[0].spot_price(9).unwrap() % PRICE_SCALE, 0);
+}
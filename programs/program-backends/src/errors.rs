@@ -50,6 +50,62 @@ pub enum CustomError {
     #[msg("Amount is negative")]
     NegativeNumber,
 
-    #[msg("Invalid fee percentage. It must be between 0.0 and 100.0.")]
+    #[msg("Invalid fee. It must be between 0 and 10000 basis points.")]
     InvalidFeePercentage,
-}
\ No newline at end of file
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Curve parameters are outside the configured limits")]
+    InvalidCurveParameters,
+
+    #[msg("Pool reserves are not backed by the vault balances")]
+    ReservesOutOfSync,
+
+    #[msg("Signer is not the configuration admin")]
+    Unauthorized,
+
+    #[msg("Fee account does not match the configured fee recipient")]
+    InvalidFeeRecipient,
+
+    #[msg("Wallets cannot refer their own trades")]
+    SelfReferral,
+
+    #[msg("Trading is paused")]
+    Paused,
+
+    #[msg("Config change delay cannot be negative")]
+    InvalidConfigChangeDelay,
+
+    #[msg("Configuration is timelocked. Queue the change instead")]
+    ConfigTimelocked,
+
+    #[msg("Queued config change is not executable yet")]
+    ConfigChangeNotReady,
+
+    #[msg("Wallet is blocked from trading")]
+    WalletBlocked,
+
+    #[msg("Pool has graduated from its bonding curve")]
+    PoolGraduated,
+
+    #[msg("Pool has not graduated to its AMM pool")]
+    PoolNotGraduated,
+
+    #[msg("Account is not in the original layout")]
+    AlreadyMigrated,
+}
+
+impl From<curve_math::MathError> for CustomError {
+    fn from(error: curve_math::MathError) -> Self {
+        match error {
+            curve_math::MathError::Overflow => CustomError::OverflowOrUnderflowOccurred,
+            curve_math::MathError::InvalidAmount => CustomError::InvalidAmount,
+            curve_math::MathError::InvalidFee => CustomError::InvalidFeePercentage,
+            curve_math::MathError::InvalidCurveParameters => CustomError::InvalidCurveParameters,
+            curve_math::MathError::NotEnoughTokenInVault => CustomError::NotEnoughTokenInVault,
+            curve_math::MathError::NotEnoughSolInVault => CustomError::NotEnoughSolInVault,
+            curve_math::MathError::TokenAmountToSellTooBig => CustomError::TokenAmountToSellTooBig,
+        }
+    }
+}
@@ -4,7 +4,8 @@ use anchor_spl::{
     token::{Mint, Token, TokenAccount},
 };
 
-use crate::state::{LiquidityPool, LiquidityPoolAccount};
+use crate::errors::CustomError;
+use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, PlatformAuthority};
 
 pub fn add_liquidity(ctx: Context<AddLiquidity>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
@@ -28,10 +29,17 @@ pub fn add_liquidity(ctx: Context<AddLiquidity>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct AddLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
     #[account(
         mut,
         seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
-        bump
+        bump,
+        constraint = pool.pause_mode(&dex_configuration_account).allows_buys() @ CustomError::Paused,
     )]
     pub pool: Account<'info, LiquidityPool>,
 
@@ -61,6 +69,13 @@ pub struct AddLiquidity<'info> {
 
     #[account(mut)]
     pub platform_authority: Signer<'info>,
+
+    #[account(
+        seeds = [PlatformAuthority::SEED_PREFIX.as_bytes(), platform_authority.key().as_ref()],
+        bump = platform_authority_entry.bump
+    )]
+    pub platform_authority_entry: Box<Account<'info, PlatformAuthority>>,
+
     pub user: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
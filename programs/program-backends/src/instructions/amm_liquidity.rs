@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    state::{
+        AmmPool, AmmPoolAccount, BlockedWallet, CurveConfiguration, LiquidityPool,
+        LiquidityProvider, PoolStatus,
+    },
+};
+
+// Deposits `sol_amount` lamports and the matching share of tokens into a graduated token's AMM
+// pool, at most `max_token_amount`. The wallet gets shares of the pool in return, which also
+// earn the pool's swap fee as it builds up in the reserves.
+pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, sol_amount: u64, max_token_amount: u64) -> Result<()> {
+    if !ctx.accounts.pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_buys() {
+        return err!(CustomError::Paused);
+    }
+    if !BlockedWallet::allows_trade(&ctx.accounts.blocked_wallet, false)? {
+        return err!(CustomError::WalletBlocked);
+    }
+
+    let amm_pool = &mut ctx.accounts.amm_pool;
+    let (token_amount, shares) = amm_pool.amm_state().quote_deposit(sol_amount).map_err(CustomError::from)?;
+    if shares == 0 {
+        return err!(CustomError::FailedToAllocateShares);
+    }
+    if token_amount > max_token_amount {
+        return Err(error!(CustomError::SlippageExceeded).with_values((max_token_amount, token_amount)));
+    }
+
+    amm_pool.transfer_sol_to_pool(
+        &ctx.accounts.user,
+        &ctx.accounts.amm_sol_vault,
+        sol_amount,
+        &ctx.accounts.system_program,
+    )?;
+    amm_pool.transfer_token_to_pool(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.amm_token_account,
+        token_amount,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+    )?;
+
+    amm_pool.reserve_sol = amm_pool
+        .reserve_sol
+        .checked_add(sol_amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    amm_pool.reserve_token = amm_pool
+        .reserve_token
+        .checked_add(token_amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    amm_pool.lp_supply = amm_pool
+        .lp_supply
+        .checked_add(shares)
+        .ok_or(CustomError::FailedToAllocateShares)?;
+
+    let liquidity_provider = &mut ctx.accounts.liquidity_provider;
+    liquidity_provider.owner = ctx.accounts.user.key();
+    liquidity_provider.amm_pool = amm_pool.key();
+    liquidity_provider.bump = ctx.bumps.liquidity_provider;
+    liquidity_provider.shares = liquidity_provider
+        .shares
+        .checked_add(shares)
+        .ok_or(CustomError::FailedToAllocateShares)?;
+
+    msg!("LIQUIDITY_DEPOSITED{{\"token_mint_address\":\"{}\",\"wallet\":\"{}\",\"sol_amount\":{},\"token_amount\":{},\"shares\":{},\"lp_supply\":{}}}",
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.user.key(),
+        sol_amount,
+        token_amount,
+        shares,
+        amm_pool.lp_supply
+    );
+    Ok(())
+}
+
+// Burns `shares` of a wallet's position for its part of the AMM pool's reserves
+pub fn withdraw_liquidity(
+    ctx: Context<WithdrawLiquidity>,
+    shares: u64,
+    min_sol_out: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    if !ctx.accounts.pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_sells() {
+        return err!(CustomError::Paused);
+    }
+    if !BlockedWallet::allows_trade(&ctx.accounts.blocked_wallet, true)? {
+        return err!(CustomError::WalletBlocked);
+    }
+
+    let liquidity_provider = &mut ctx.accounts.liquidity_provider;
+    if shares > liquidity_provider.shares {
+        return Err(error!(CustomError::InsufficientShares).with_values((liquidity_provider.shares, shares)));
+    }
+
+    let amm_pool = &mut ctx.accounts.amm_pool;
+    let (token_amount, sol_amount) = amm_pool.amm_state().quote_withdraw(shares).map_err(CustomError::from)?;
+    if sol_amount < min_sol_out {
+        return Err(error!(CustomError::SlippageExceeded).with_values((min_sol_out, sol_amount)));
+    }
+    if token_amount < min_tokens_out {
+        return Err(error!(CustomError::SlippageExceeded).with_values((min_tokens_out, token_amount)));
+    }
+
+    amm_pool.transfer_token_from_pool(
+        &ctx.accounts.amm_token_account,
+        &ctx.accounts.user_token_account,
+        token_amount,
+        &ctx.accounts.token_program,
+    )?;
+    amm_pool.transfer_sol_from_pool(
+        &ctx.accounts.amm_sol_vault,
+        ctx.accounts.user.to_account_info(),
+        sol_amount,
+        ctx.bumps.amm_sol_vault,
+        &ctx.accounts.system_program,
+    )?;
+
+    liquidity_provider.shares -= shares;
+    amm_pool.lp_supply = amm_pool
+        .lp_supply
+        .checked_sub(shares)
+        .ok_or(CustomError::FailedToDeallocateShares)?;
+    amm_pool.reserve_sol = amm_pool
+        .reserve_sol
+        .checked_sub(sol_amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    amm_pool.reserve_token = amm_pool
+        .reserve_token
+        .checked_sub(token_amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    msg!("LIQUIDITY_WITHDRAWN{{\"token_mint_address\":\"{}\",\"wallet\":\"{}\",\"sol_amount\":{},\"token_amount\":{},\"shares\":{},\"lp_supply\":{}}}",
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.user.key(),
+        sol_amount,
+        token_amount,
+        shares,
+        amm_pool.lp_supply
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Migrated @ CustomError::PoolNotGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [AmmPool::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub amm_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [AmmPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub amm_sol_vault: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        space = LiquidityProvider::ACCOUNT_SIZE,
+        payer = user,
+        seeds = [LiquidityProvider::SEED_PREFIX.as_bytes(), amm_pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub liquidity_provider: Box<Account<'info, LiquidityProvider>>,
+
+    /// CHECK: may be uninitialized, read by `BlockedWallet::allows_trade`
+    #[account(
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Migrated @ CustomError::PoolNotGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [AmmPool::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub amm_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [AmmPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub amm_sol_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityProvider::SEED_PREFIX.as_bytes(), amm_pool.key().as_ref(), user.key().as_ref()],
+        bump = liquidity_provider.bump,
+        has_one = amm_pool,
+    )]
+    pub liquidity_provider: Box<Account<'info, LiquidityProvider>>,
+
+    /// CHECK: may be uninitialized, read by `BlockedWallet::allows_trade`
+    #[account(
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
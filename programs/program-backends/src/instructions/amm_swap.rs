@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::CustomError,
+    state::{AmmPool, AmmPoolAccount, BlockedWallet, CurveConfiguration, LiquidityPool, PoolStatus},
+};
+
+// Buys tokens from a graduated token's AMM pool with `sol_in` lamports
+pub fn amm_buy(ctx: Context<AmmSwap>, sol_in: u64, min_tokens_out: u64) -> Result<()> {
+    if !ctx.accounts.pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_buys() {
+        return err!(CustomError::Paused);
+    }
+    ctx.accounts.check_blocked(false)?;
+
+    let amm_pool = &mut ctx.accounts.amm_pool;
+    let tokens_out = amm_pool.amm_state().quote_buy(sol_in).map_err(CustomError::from)?;
+    if tokens_out < min_tokens_out {
+        return Err(error!(CustomError::SlippageExceeded).with_values((min_tokens_out, tokens_out)));
+    }
+
+    amm_pool.transfer_sol_to_pool(
+        &ctx.accounts.user,
+        &ctx.accounts.amm_sol_vault,
+        sol_in,
+        &ctx.accounts.system_program,
+    )?;
+    amm_pool.transfer_token_from_pool(
+        &ctx.accounts.amm_token_account,
+        &ctx.accounts.user_token_account,
+        tokens_out,
+        &ctx.accounts.token_program,
+    )?;
+
+    amm_pool.reserve_sol = amm_pool
+        .reserve_sol
+        .checked_add(sol_in)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    amm_pool.reserve_token = amm_pool
+        .reserve_token
+        .checked_sub(tokens_out)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    ctx.accounts.log_swap("BUY", sol_in, tokens_out)
+}
+
+// Sells `tokens_in` tokens into a graduated token's AMM pool
+pub fn amm_sell(ctx: Context<AmmSwap>, tokens_in: u64, min_sol_out: u64) -> Result<()> {
+    if !ctx.accounts.pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_sells() {
+        return err!(CustomError::Paused);
+    }
+    ctx.accounts.check_blocked(true)?;
+
+    let amm_pool = &mut ctx.accounts.amm_pool;
+    let sol_out = amm_pool.amm_state().quote_sell(tokens_in).map_err(CustomError::from)?;
+    if sol_out < min_sol_out {
+        return Err(error!(CustomError::SlippageExceeded).with_values((min_sol_out, sol_out)));
+    }
+
+    amm_pool.transfer_token_to_pool(
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.amm_token_account,
+        tokens_in,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+    )?;
+    amm_pool.transfer_sol_from_pool(
+        &ctx.accounts.amm_sol_vault,
+        ctx.accounts.user.to_account_info(),
+        sol_out,
+        ctx.bumps.amm_sol_vault,
+        &ctx.accounts.system_program,
+    )?;
+
+    amm_pool.reserve_token = amm_pool
+        .reserve_token
+        .checked_add(tokens_in)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+    amm_pool.reserve_sol = amm_pool
+        .reserve_sol
+        .checked_sub(sol_out)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    ctx.accounts.log_swap("SELL", sol_out, tokens_in)
+}
+
+#[derive(Accounts)]
+pub struct AmmSwap<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Migrated @ CustomError::PoolNotGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [AmmPool::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = amm_pool.bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub amm_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [AmmPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub amm_sol_vault: AccountInfo<'info>,
+
+    /// CHECK: may be uninitialized, read by `BlockedWallet::allows_trade`
+    #[account(
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AmmSwap<'info> {
+    fn check_blocked(&self, selling: bool) -> Result<()> {
+        if !BlockedWallet::allows_trade(&self.blocked_wallet, selling)? {
+            return err!(CustomError::WalletBlocked);
+        }
+        Ok(())
+    }
+
+    fn log_swap(&self, side: &str, sol_amount: u64, token_amount: u64) -> Result<()> {
+        msg!("AMM_SWAP{{\"token_mint_address\":\"{}\",\"type\":\"{}\",\"sol_amount\":{},\"token_amount\":{},\"price_lamports_e9\":{},\"wallet\":\"{}\"}}",
+            self.token_mint.key(),
+            side,
+            sol_amount,
+            token_amount,
+            self.amm_pool.spot_price(self.token_mint.decimals)?,
+            self.user.key()
+        );
+        Ok(())
+    }
+}
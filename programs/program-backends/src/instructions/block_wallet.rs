@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{BlockedWallet, CurveConfiguration},
+};
+
+// Blocks `wallet` from buying, and from selling too unless `allow_sells` is set. Calling it
+// again for a blocked wallet updates `allow_sells`.
+pub fn block_wallet(ctx: Context<BlockWallet>, wallet: Pubkey, allow_sells: bool) -> Result<()> {
+    let blocked_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.blocked_wallet.set_inner(BlockedWallet {
+        wallet,
+        allow_sells,
+        blocked_at,
+        bump: ctx.bumps.blocked_wallet,
+    });
+
+    msg!("WALLET_BLOCKED{{\"wallet\":\"{}\",\"allow_sells\":{},\"blocked_at\":{}}}",
+        wallet,
+        allow_sells,
+        blocked_at
+    );
+    Ok(())
+}
+
+pub fn unblock_wallet(ctx: Context<UnblockWallet>) -> Result<()> {
+    msg!("WALLET_UNBLOCKED{{\"wallet\":\"{}\"}}", ctx.accounts.blocked_wallet.wallet);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct BlockWallet<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init_if_needed,
+        space = BlockedWallet::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), wallet.as_ref()],
+        bump
+    )]
+    pub blocked_wallet: Box<Account<'info, BlockedWallet>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnblockWallet<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), blocked_wallet.wallet.as_ref()],
+        bump = blocked_wallet.bump
+    )]
+    pub blocked_wallet: Box<Account<'info, BlockedWallet>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -3,13 +3,14 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
 };
-use solana_program::pubkey;
 
-use crate::state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount};
-
-const TEAM_WALLET_PUBKEY: Pubkey = pubkey!("6XF158v9uXWL7dpJnkJFHKpZgzmLXX5HoH4vG5hPsmmP"); 
+use crate::errors::CustomError;
+use crate::state::{
+    BlockedWallet, CreatorFeeVault, CurveConfiguration, FeeDiscount, LiquidityPool,
+    LiquidityPoolAccount, PoolStatus, ProtocolTreasury, Referrer,
+};
 
-pub fn buy(ctx: Context<Buy>, amount: u64) -> Result<()> {
+pub fn buy(ctx: Context<Buy>, amount: u64, min_tokens_out: u64) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
 
     let token_one_accounts = (
@@ -17,16 +18,24 @@ pub fn buy(ctx: Context<Buy>, amount: u64) -> Result<()> {
         &mut *ctx.accounts.pool_token_account,
         &mut *ctx.accounts.user_token_account,
     );
-    let fees = ctx.accounts.dex_configuration_account.fees;
+    let discount_bps = ctx.accounts.dex_configuration_account.discount_bps(
+        ctx.accounts.fee_discount.as_deref().map(|account| &**account),
+        ctx.accounts.platform_token_account.as_deref().map(|account| &**account),
+    )?;
+    let fees = pool.buy_fees(&ctx.accounts.dex_configuration_account.fees, discount_bps, false)?;
     pool.buy(
         token_one_accounts,
         &mut ctx.accounts.pool_sol_vault,
-        ctx.accounts.team_account.clone(),
+        &mut ctx.accounts.dex_configuration_account,
+        &mut ctx.accounts.protocol_treasury.to_account_info(),
+        &mut ctx.accounts.creator_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
         amount,
+        min_tokens_out,
         &ctx.accounts.user,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
-        fees
+        fees,
     )?;
     Ok(())
 }
@@ -43,7 +52,9 @@ pub struct Buy<'info> {
     #[account(
         mut,
         seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
-        bump = pool.bump
+        bump = pool.bump,
+        constraint = pool.pause_mode(&dex_configuration_account).allows_buys() @ CustomError::Paused,
+        constraint = pool.status == PoolStatus::Trading @ CustomError::PoolGraduated,
     )]
     pub pool: Box<Account<'info, LiquidityPool>>,
 
@@ -65,6 +76,48 @@ pub struct Buy<'info> {
     )]
     pub pool_sol_vault: AccountInfo<'info>,
 
+    #[account(
+        mut,
+        seeds = [CreatorFeeVault::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = creator_fee_vault.bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolTreasury::SEED.as_bytes()],
+        bump = protocol_treasury.bump
+    )]
+    pub protocol_treasury: Box<Account<'info, ProtocolTreasury>>,
+
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        constraint = referrer.wallet != user.key() @ CustomError::SelfReferral
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+    #[account(
+        seeds = [FeeDiscount::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump = fee_discount.bump
+    )]
+    pub fee_discount: Option<Box<Account<'info, FeeDiscount>>>,
+
+    #[account(
+        token::mint = dex_configuration_account.holder_discount.platform_mint,
+        token::authority = user
+    )]
+    pub platform_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: may be uninitialized, read by `BlockedWallet::allows_trade`
+    #[account(
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump,
+        constraint = BlockedWallet::allows_trade(&blocked_wallet, false)? @ CustomError::WalletBlocked,
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -79,9 +132,4 @@ pub struct Buy<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-
-    #[account(mut, address = TEAM_WALLET_PUBKEY)]
-    pub team_account: UncheckedAccount<'info>
-
-
 }
\ No newline at end of file
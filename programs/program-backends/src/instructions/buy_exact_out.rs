@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::Buy;
+use crate::state::LiquidityPoolAccount;
+
+pub fn buy_exact_out(ctx: Context<Buy>, token_amount: u64, max_sol_in: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let token_one_accounts = (
+        &mut *ctx.accounts.token_mint,
+        &mut *ctx.accounts.pool_token_account,
+        &mut *ctx.accounts.user_token_account,
+    );
+    let discount_bps = ctx.accounts.dex_configuration_account.discount_bps(
+        ctx.accounts.fee_discount.as_deref().map(|account| &**account),
+        ctx.accounts.platform_token_account.as_deref().map(|account| &**account),
+    )?;
+    let fees = pool.buy_fees(&ctx.accounts.dex_configuration_account.fees, discount_bps, false)?;
+    pool.buy_exact_out(
+        token_one_accounts,
+        &mut ctx.accounts.pool_sol_vault,
+        &mut ctx.accounts.dex_configuration_account,
+        &mut ctx.accounts.protocol_treasury.to_account_info(),
+        &mut ctx.accounts.creator_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        token_amount,
+        max_sol_in,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        fees,
+    )?;
+    Ok(())
+}
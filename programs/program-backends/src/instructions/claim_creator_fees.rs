@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{CreatorFeeVault, LiquidityPool},
+};
+
+// Pays everything above rent in the pool's creator fee vault to the pool creator. Anyone can
+// crank it since the lamports can only go to `pool.creator`.
+pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let creator_fee_vault = ctx.accounts.creator_fee_vault.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(creator_fee_vault.data_len());
+    let amount = creator_fee_vault.lamports().saturating_sub(rent_exempt);
+    if amount == 0 {
+        return err!(CustomError::InvalidAmount);
+    }
+
+    **creator_fee_vault.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.creator.try_borrow_mut_lamports()? += amount;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.creator_fees_claimed = pool
+        .creator_fees_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    msg!("CREATOR_FEES_CLAIMED{{\"token_mint_address\":\"{}\",\"creator\":\"{}\",\"amount\":{},\"total_accrued\":{},\"total_claimed\":{}}}",
+        ctx.accounts.token_mint.key(),
+        pool.creator,
+        amount,
+        pool.creator_fees_accrued,
+        pool.creator_fees_claimed
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = creator @ CustomError::NotCreator,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [CreatorFeeVault::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = creator_fee_vault.bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+}
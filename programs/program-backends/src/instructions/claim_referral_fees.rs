@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CustomError, state::Referrer};
+
+// Pays everything above rent in the referrer account to its wallet
+pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let referrer_info = ctx.accounts.referrer.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(referrer_info.data_len());
+    let amount = referrer_info.lamports().saturating_sub(rent_exempt);
+    if amount == 0 {
+        return err!(CustomError::InvalidAmount);
+    }
+
+    **referrer_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.wallet.try_borrow_mut_lamports()? += amount;
+
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.claimed = referrer
+        .claimed
+        .checked_add(amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    msg!("REFERRAL_FEES_CLAIMED{{\"wallet\":\"{}\",\"amount\":{},\"referred_volume\":{},\"lifetime_earnings\":{},\"claimed\":{}}}",
+        referrer.wallet,
+        amount,
+        referrer.referred_volume,
+        referrer.lifetime_earnings,
+        referrer.claimed
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), wallet.key().as_ref()],
+        bump = referrer.bump,
+        has_one = wallet,
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{ConfigUpdate, CurveConfiguration, PendingConfigChange},
+};
+
+// Queues `update` to be applied once the configuration's change delay has passed. Only one
+// change can be pending; cancel it to queue a different one.
+pub fn queue_config_change(ctx: Context<QueueConfigChange>, update: ConfigUpdate) -> Result<()> {
+    update.validate()?;
+
+    let queued_at = Clock::get()?.unix_timestamp;
+    let executable_at = ctx.accounts.dex_configuration_account.change_executable_at(queued_at)?;
+
+    ctx.accounts.pending_config_change.set_inner(PendingConfigChange {
+        update,
+        queued_at,
+        executable_at,
+        bump: ctx.bumps.pending_config_change,
+    });
+
+    msg!("CONFIG_CHANGE_QUEUED{{\"update\":\"{:?}\",\"queued_at\":{},\"executable_at\":{}}}",
+        update,
+        queued_at,
+        executable_at
+    );
+    Ok(())
+}
+
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending = &ctx.accounts.pending_config_change;
+    let now = Clock::get()?.unix_timestamp;
+    if now < pending.executable_at {
+        return err!(CustomError::ConfigChangeNotReady);
+    }
+
+    let update = pending.update;
+    ctx.accounts.dex_configuration_account.apply(&update);
+
+    msg!("CONFIG_CHANGE_EXECUTED{{\"update\":\"{:?}\",\"queued_at\":{},\"executed_at\":{}}}",
+        update,
+        pending.queued_at,
+        now
+    );
+    Ok(())
+}
+
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    msg!("CONFIG_CHANGE_CANCELLED{{\"update\":\"{:?}\",\"queued_at\":{}}}",
+        ctx.accounts.pending_config_change.update,
+        ctx.accounts.pending_config_change.queued_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = PendingConfigChange::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PendingConfigChange::SEED.as_bytes()],
+        bump
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingConfigChange::SEED.as_bytes()],
+        bump = pending_config_change.bump
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingConfigChange::SEED.as_bytes()],
+        bump = pending_config_change.bump
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
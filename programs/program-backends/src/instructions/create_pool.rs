@@ -1,23 +1,51 @@
-use crate::state::*;
+use crate::{state::*, utils::CurveKind};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
 };
 
-pub fn create_pool(ctx: Context<CreateLiquidityPool>) -> Result<()> {
+pub fn create_pool(
+    ctx: Context<CreateLiquidityPool>,
+    virtual_sol_reserves: u64,
+    proportion: u64,
+    initial_lamports: u64,
+    curve_kind: CurveKind,
+) -> Result<()> {
+    ctx.accounts
+        .dex_configuration_account
+        .curve_limits
+        .check(virtual_sol_reserves, proportion, initial_lamports)?;
+    curve_kind.validate(proportion)?;
+
     let pool = &mut ctx.accounts.pool;
 
     pool.set_inner(LiquidityPool::new(
         ctx.accounts.payer.key(),
         ctx.accounts.token_mint.key(),
         ctx.bumps.pool,
+        virtual_sol_reserves,
+        proportion,
+        initial_lamports,
+        curve_kind,
+        ctx.accounts.dex_configuration_account.fees.launch_fee,
+        ctx.accounts.dex_configuration_account.graduation,
     ));
+
+    let creator_fee_vault = &mut ctx.accounts.creator_fee_vault;
+    creator_fee_vault.pool = pool.key();
+    creator_fee_vault.bump = ctx.bumps.creator_fee_vault;
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct CreateLiquidityPool<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
     #[account(
         init,
         space = LiquidityPool::ACCOUNT_SIZE,
@@ -38,6 +66,15 @@ pub struct CreateLiquidityPool<'info> {
     )]
     pub pool_token_account: Box<Account<'info, TokenAccount>>,
 
+    #[account(
+        init,
+        space = CreatorFeeVault::ACCOUNT_SIZE,
+        payer = payer,
+        seeds = [CreatorFeeVault::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
     pub token_program: Program<'info, Token>,
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    instructions::{apply_pool_fee_override, check_discount_bps, log_fee_discount},
+    state::{CurveConfiguration, FeeDiscount, LiquidityPool, PendingFeeDiscount, PendingPoolFeeOverride, PoolFeeOverride},
+};
+
+// Queues a pool fee override, or with `None` its removal, to be applied once the
+// configuration's change delay has passed. This is how fee overrides change while the
+// configuration is timelocked.
+pub fn queue_pool_fee_override(
+    ctx: Context<QueuePoolFeeOverride>,
+    fee_override: Option<PoolFeeOverride>,
+) -> Result<()> {
+    if let Some(fee_override) = fee_override {
+        fee_override.validate()?;
+    }
+
+    let queued_at = Clock::get()?.unix_timestamp;
+    let executable_at = ctx.accounts.dex_configuration_account.change_executable_at(queued_at)?;
+
+    ctx.accounts.pending_pool_fee_override.set_inner(PendingPoolFeeOverride {
+        fee_override,
+        queued_at,
+        executable_at,
+        bump: ctx.bumps.pending_pool_fee_override,
+    });
+
+    msg!("POOL_FEE_OVERRIDE_QUEUED{{\"token_mint_address\":\"{}\",\"fee_override\":\"{:?}\",\"queued_at\":{},\"executable_at\":{}}}",
+        ctx.accounts.token_mint.key(),
+        fee_override,
+        queued_at,
+        executable_at
+    );
+    Ok(())
+}
+
+pub fn execute_pool_fee_override(ctx: Context<ExecutePoolFeeOverride>) -> Result<()> {
+    let pending = &ctx.accounts.pending_pool_fee_override;
+    let now = Clock::get()?.unix_timestamp;
+    if now < pending.executable_at {
+        return err!(CustomError::ConfigChangeNotReady);
+    }
+
+    apply_pool_fee_override(
+        &mut ctx.accounts.pool,
+        ctx.accounts.token_mint.key(),
+        &ctx.accounts.dex_configuration_account.fees,
+        pending.fee_override,
+    );
+    Ok(())
+}
+
+pub fn cancel_pool_fee_override(ctx: Context<CancelPoolFeeOverride>) -> Result<()> {
+    msg!("POOL_FEE_OVERRIDE_CANCELLED{{\"token_mint_address\":\"{}\",\"fee_override\":\"{:?}\",\"queued_at\":{}}}",
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.pending_pool_fee_override.fee_override,
+        ctx.accounts.pending_pool_fee_override.queued_at
+    );
+    Ok(())
+}
+
+// Queues the same change as `set_fee_discount` to be applied once the configuration's change
+// delay has passed
+pub fn queue_fee_discount(
+    ctx: Context<QueueFeeDiscount>,
+    wallet: Pubkey,
+    discount_bps: u16,
+    expires_at: i64,
+) -> Result<()> {
+    check_discount_bps(discount_bps)?;
+
+    let queued_at = Clock::get()?.unix_timestamp;
+    let executable_at = ctx.accounts.dex_configuration_account.change_executable_at(queued_at)?;
+
+    ctx.accounts.pending_fee_discount.set_inner(PendingFeeDiscount {
+        wallet,
+        discount_bps,
+        expires_at,
+        queued_at,
+        executable_at,
+        bump: ctx.bumps.pending_fee_discount,
+    });
+
+    msg!("FEE_DISCOUNT_QUEUED{{\"wallet\":\"{}\",\"discount_bps\":{},\"expires_at\":{},\"queued_at\":{},\"executable_at\":{}}}",
+        wallet,
+        discount_bps,
+        expires_at,
+        queued_at,
+        executable_at
+    );
+    Ok(())
+}
+
+pub fn execute_fee_discount(ctx: Context<ExecuteFeeDiscount>) -> Result<()> {
+    let pending = &ctx.accounts.pending_fee_discount;
+    let now = Clock::get()?.unix_timestamp;
+    if now < pending.executable_at {
+        return err!(CustomError::ConfigChangeNotReady);
+    }
+
+    ctx.accounts.fee_discount.set_inner(FeeDiscount {
+        wallet: pending.wallet,
+        discount_bps: pending.discount_bps,
+        expires_at: pending.expires_at,
+        bump: ctx.bumps.fee_discount,
+    });
+    log_fee_discount(&ctx.accounts.fee_discount);
+    Ok(())
+}
+
+pub fn cancel_fee_discount(ctx: Context<CancelFeeDiscount>) -> Result<()> {
+    let pending = &ctx.accounts.pending_fee_discount;
+    msg!("FEE_DISCOUNT_CANCELLED{{\"wallet\":\"{}\",\"discount_bps\":{},\"queued_at\":{}}}",
+        pending.wallet,
+        pending.discount_bps,
+        pending.queued_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueuePoolFeeOverride<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        space = PendingPoolFeeOverride::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PendingPoolFeeOverride::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pending_pool_fee_override: Box<Account<'info, PendingPoolFeeOverride>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecutePoolFeeOverride<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingPoolFeeOverride::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pending_pool_fee_override.bump
+    )]
+    pub pending_pool_fee_override: Box<Account<'info, PendingPoolFeeOverride>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPoolFeeOverride<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingPoolFeeOverride::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pending_pool_fee_override.bump
+    )]
+    pub pending_pool_fee_override: Box<Account<'info, PendingPoolFeeOverride>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct QueueFeeDiscount<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = PendingFeeDiscount::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PendingFeeDiscount::SEED_PREFIX.as_bytes(), wallet.as_ref()],
+        bump
+    )]
+    pub pending_fee_discount: Box<Account<'info, PendingFeeDiscount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteFeeDiscount<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingFeeDiscount::SEED_PREFIX.as_bytes(), pending_fee_discount.wallet.as_ref()],
+        bump = pending_fee_discount.bump
+    )]
+    pub pending_fee_discount: Box<Account<'info, PendingFeeDiscount>>,
+
+    #[account(
+        init_if_needed,
+        space = FeeDiscount::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [FeeDiscount::SEED_PREFIX.as_bytes(), pending_fee_discount.wallet.as_ref()],
+        bump
+    )]
+    pub fee_discount: Box<Account<'info, FeeDiscount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelFeeDiscount<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingFeeDiscount::SEED_PREFIX.as_bytes(), pending_fee_discount.wallet.as_ref()],
+        bump = pending_fee_discount.bump
+    )]
+    pub pending_fee_discount: Box<Account<'info, PendingFeeDiscount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use curve_math::AmmState;
+
+use crate::{
+    errors::CustomError,
+    state::{AmmPool, LiquidityPool, LiquidityPoolAccount, PoolStatus},
+};
+
+// Opens an `AmmPool` at the price the bonding curve left off at: it gets all of a graduated
+// pool's lamports and as many of its tokens as that price calls for, and the rest of the pool's
+// tokens are burned. Anyone can crank it once the pool is `Graduated`; the opening LP shares are
+// locked for good.
+pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let available = ctx.accounts.pool_token_account.amount;
+    let reserve_sol = ctx.accounts.pool_sol_vault.lamports();
+    let opening = AmmState::opening(
+        reserve_sol,
+        available,
+        pool.last_price,
+        ctx.accounts.token_mint.decimals,
+        pool.graduation.amm_fee_bps,
+    )
+    .map_err(CustomError::from)?;
+    let burned = available - opening.reserve_token;
+
+    pool.transfer_token_from_pool(
+        &ctx.accounts.pool_token_account,
+        &ctx.accounts.amm_token_account,
+        opening.reserve_token,
+        &ctx.accounts.token_program,
+    )?;
+    if burned > 0 {
+        pool.burn_token_from_pool(
+            &ctx.accounts.token_mint,
+            &ctx.accounts.pool_token_account,
+            burned,
+            &ctx.accounts.token_program,
+        )?;
+    }
+    pool.transfer_fee_from_pool(
+        &mut ctx.accounts.pool_sol_vault,
+        ctx.accounts.amm_sol_vault.to_account_info(),
+        reserve_sol,
+        ctx.bumps.pool_sol_vault,
+        &ctx.accounts.system_program,
+    )?;
+    pool.update_reserves(0, 0)?;
+    pool.status = PoolStatus::Migrated;
+
+    ctx.accounts.amm_pool.set_inner(AmmPool {
+        token: ctx.accounts.token_mint.key(),
+        reserve_token: opening.reserve_token,
+        reserve_sol,
+        lp_supply: opening.lp_supply,
+        locked_lp: opening.lp_supply,
+        fee_bps: opening.fee_bps,
+        bump: ctx.bumps.amm_pool,
+    });
+
+    msg!("POOL_MIGRATED{{\"token_mint_address\":\"{}\",\"reserve_sol\":{},\"reserve_token\":{},\"burned\":{},\"lp_supply\":{},\"price_lamports_e9\":{}}}",
+        ctx.accounts.token_mint.key(),
+        reserve_sol,
+        opening.reserve_token,
+        burned,
+        opening.lp_supply,
+        ctx.accounts.amm_pool.spot_price(ctx.accounts.token_mint.decimals)?
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Graduate<'info> {
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Graduated @ CustomError::PoolNotGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(mut)]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        space = AmmPool::ACCOUNT_SIZE,
+        payer = payer,
+        seeds = [AmmPool::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub amm_pool: Box<Account<'info, AmmPool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = amm_pool
+    )]
+    pub amm_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [AmmPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub amm_sol_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
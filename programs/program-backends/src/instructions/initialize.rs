@@ -1,16 +1,23 @@
-use crate::{errors::CustomError, state::*};
+use crate::{errors::CustomError, program::AiAgent, state::*};
 use anchor_lang::prelude::*;
 
 pub fn initialize(
     ctx: Context<InitializeCurveConfiguration>,
-    fees: f64,
+    fees: FeeSettings,
+    curve_limits: CurveLimits,
+    fee_recipient: Pubkey,
 ) -> Result<()> {
     let dex_config = &mut ctx.accounts.dex_configuration_account;
-    if fees < 0_f64 || fees > 100_f64 {
-        return err!(CustomError::InvalidFee);
-    }
+    fees.validate()?;
+    curve_limits.validate()?;
 
-    dex_config.set_inner(CurveConfiguration::new(fees));
+    dex_config.set_inner(CurveConfiguration::new(
+        fees,
+        curve_limits,
+        ctx.accounts.admin.key(),
+        fee_recipient,
+    ));
+    ctx.accounts.protocol_treasury.bump = ctx.bumps.protocol_treasury;
 
     Ok(())
 }
@@ -26,8 +33,24 @@ pub struct InitializeCurveConfiguration<'info> {
     )]
     pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
 
+    #[account(
+        init,
+        space = ProtocolTreasury::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [ProtocolTreasury::SEED.as_bytes()],
+        bump,
+    )]
+    pub protocol_treasury: Box<Account<'info, ProtocolTreasury>>,
+
     #[account(mut)]
     pub admin: Signer<'info>,
+
+    // Only the program's upgrade authority may create the configuration
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AiAgent>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ CustomError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
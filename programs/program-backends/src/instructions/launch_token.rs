@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{
+        create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
+        Metadata,
+    },
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{errors::CustomError, state::*, utils::CurveKind};
+
+// Creates a token and its pool in one go: the mint with the pool as mint authority, its
+// Metaplex metadata, the fixed supply minted into the pool, the seeded vault and, when
+// `initial_buy` is non-zero, the creator's first buy. Nothing can land between the steps, so
+// that buy can't be a snipe and doesn't pay the launch fee.
+#[allow(clippy::too_many_arguments)]
+pub fn launch_token(
+    ctx: Context<LaunchToken>,
+    name: String,
+    symbol: String,
+    uri: String,
+    virtual_sol_reserves: u64,
+    proportion: u64,
+    initial_lamports: u64,
+    curve_kind: CurveKind,
+    initial_buy: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    ctx.accounts
+        .dex_configuration_account
+        .curve_limits
+        .check(virtual_sol_reserves, proportion, initial_lamports)?;
+    curve_kind.validate(proportion)?;
+
+    let token_mint = ctx.accounts.token_mint.key();
+    let pool = &mut ctx.accounts.pool;
+    pool.set_inner(LiquidityPool::new(
+        ctx.accounts.user.key(),
+        token_mint,
+        ctx.bumps.pool,
+        virtual_sol_reserves,
+        proportion,
+        initial_lamports,
+        curve_kind,
+        ctx.accounts.dex_configuration_account.fees.launch_fee,
+        ctx.accounts.dex_configuration_account.graduation,
+    ));
+
+    let creator_fee_vault = &mut ctx.accounts.creator_fee_vault;
+    creator_fee_vault.pool = pool.key();
+    creator_fee_vault.bump = ctx.bumps.creator_fee_vault;
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                mint_authority: pool.to_account_info(),
+                payer: ctx.accounts.user.to_account_info(),
+                update_authority: ctx.accounts.platform_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &[&[
+                LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
+                token_mint.as_ref(),
+                &[pool.bump],
+            ]],
+        ),
+        DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
+    pool.mint_liquidity(
+        (&mut *ctx.accounts.token_mint, &mut *ctx.accounts.pool_token_account),
+        &mut ctx.accounts.pool_sol_vault,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    msg!("TOKEN_LAUNCHED{{\"token_mint_address\":\"{}\",\"creator\":\"{}\",\"name\":\"{}\",\"symbol\":\"{}\",\"uri\":\"{}\",\"initial_lamports\":{}}}",
+        token_mint,
+        pool.creator,
+        name,
+        symbol,
+        uri,
+        initial_lamports
+    );
+
+    if initial_buy == 0 {
+        return Ok(());
+    }
+
+    let discount_bps = ctx.accounts.dex_configuration_account.discount_bps(
+        ctx.accounts.fee_discount.as_deref().map(|account| &**account),
+        ctx.accounts.platform_token_account.as_deref().map(|account| &**account),
+    )?;
+    let fees = pool.buy_fees(&ctx.accounts.dex_configuration_account.fees, discount_bps, true)?;
+    pool.buy(
+        (
+            &mut *ctx.accounts.token_mint,
+            &mut *ctx.accounts.pool_token_account,
+            &mut *ctx.accounts.user_token_account,
+        ),
+        &mut ctx.accounts.pool_sol_vault,
+        &mut ctx.accounts.dex_configuration_account,
+        &mut ctx.accounts.protocol_treasury.to_account_info(),
+        &mut ctx.accounts.creator_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        initial_buy,
+        min_tokens_out,
+        &ctx.accounts.user,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        fees,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LaunchToken<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.paused.allows_buys() @ CustomError::Paused,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = LiquidityPool::ACCOUNT_SIZE,
+        payer = user,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        init,
+        payer = user,
+        mint::decimals = LiquidityPool::TOKEN_DECIMALS,
+        mint::authority = pool,
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [LiquidityPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        space = CreatorFeeVault::ACCOUNT_SIZE,
+        payer = user,
+        seeds = [CreatorFeeVault::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolTreasury::SEED.as_bytes()],
+        bump = protocol_treasury.bump
+    )]
+    pub protocol_treasury: Box<Account<'info, ProtocolTreasury>>,
+
+    #[account(
+        mut,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), referrer.wallet.as_ref()],
+        bump = referrer.bump,
+        constraint = referrer.wallet != user.key() @ CustomError::SelfReferral
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+    #[account(
+        seeds = [FeeDiscount::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump = fee_discount.bump
+    )]
+    pub fee_discount: Option<Box<Account<'info, FeeDiscount>>>,
+
+    #[account(
+        token::mint = dex_configuration_account.holder_discount.platform_mint,
+        token::authority = user
+    )]
+    pub platform_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: may be uninitialized, read by `BlockedWallet::allows_trade`
+    #[account(
+        seeds = [BlockedWallet::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump,
+        constraint = BlockedWallet::allows_trade(&blocked_wallet, false)? @ CustomError::WalletBlocked,
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub platform_authority: Signer<'info>,
+
+    #[account(
+        seeds = [PlatformAuthority::SEED_PREFIX.as_bytes(), platform_authority.key().as_ref()],
+        bump = platform_authority_entry.bump
+    )]
+    pub platform_authority_entry: Box<Account<'info, PlatformAuthority>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
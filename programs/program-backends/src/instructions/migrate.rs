@@ -0,0 +1,226 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    program::AiAgent,
+    state::*,
+    utils::{CurveKind, LaunchClock, LaunchDecay, LaunchFeeSchedule},
+};
+
+// Accounts written by the original program predate per-pool curve parameters and everything
+// stored after them. These instructions grow them to the current layout in place, so an
+// upgraded deployment keeps its existing configuration address and pools.
+
+// Discriminator (8) + fees (f64, 8), allocated with 32 bytes of padding
+const LEGACY_CONFIG_SIZE: usize = 8 + 32 + 8;
+
+// Discriminator (8) + Pubkey (32) + Pubkey (32) + totalsupply (8)
+// + reserve one (8) + reserve two (8) + Bump (1)
+const LEGACY_POOL_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+// Curve every original pool traded on: the quadratic curve over a 25 SOL virtual reserve
+const LEGACY_VIRTUAL_SOL_RESERVES: u64 = 25_000_000_000;
+const LEGACY_PROPORTION: u64 = 1280;
+const LEGACY_INITIAL_LAMPORTS: u64 = 10_000_000;
+
+#[derive(AnchorDeserialize)]
+struct LegacyLiquidityPool {
+    creator: Pubkey,
+    token: Pubkey,
+    total_supply: u64,
+    reserve_token: u64,
+    // Not kept in step with the vault, which is the only source of truth for the lamports
+    _reserve_sol: u64,
+    bump: u8,
+}
+
+pub fn migrate_config(
+    ctx: Context<MigrateCurveConfiguration>,
+    fees: FeeSettings,
+    curve_limits: CurveLimits,
+    fee_recipient: Pubkey,
+) -> Result<()> {
+    fees.validate()?;
+    curve_limits.validate()?;
+
+    let config = &ctx.accounts.dex_configuration_account;
+    check_legacy(config, CurveConfiguration::discriminator(), LEGACY_CONFIG_SIZE)?;
+    grow(config, CurveConfiguration::ACCOUNT_SIZE, &ctx.accounts.admin, &ctx.accounts.system_program)?;
+
+    // The original flat fee has no counterpart in `FeeSettings`, so it is replaced outright
+    let migrated = CurveConfiguration::new(fees, curve_limits, ctx.accounts.admin.key(), fee_recipient);
+    migrated.try_serialize(&mut &mut config.try_borrow_mut_data()?[..])?;
+    ctx.accounts.protocol_treasury.bump = ctx.bumps.protocol_treasury;
+
+    msg!("CONFIG_MIGRATED{{\"admin\":\"{}\"}}", ctx.accounts.admin.key());
+    Ok(())
+}
+
+pub fn migrate_pool(ctx: Context<MigrateLiquidityPool>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    check_legacy(pool, LiquidityPool::discriminator(), LEGACY_POOL_SIZE)?;
+    let migrated = migrated_pool(
+        &pool.try_borrow_data()?,
+        ctx.accounts.dex_configuration_account.graduation,
+        ctx.accounts.pool_sol_vault.lamports(),
+        &Rent::get()?,
+        ctx.accounts.token_mint.decimals,
+    )?;
+    grow(pool, LiquidityPool::ACCOUNT_SIZE, &ctx.accounts.admin, &ctx.accounts.system_program)?;
+    migrated.try_serialize(&mut &mut pool.try_borrow_mut_data()?[..])?;
+
+    // Original pools had no creator fees, so the vault starts out empty
+    let creator_fee_vault = &mut ctx.accounts.creator_fee_vault;
+    creator_fee_vault.pool = pool.key();
+    creator_fee_vault.bump = ctx.bumps.creator_fee_vault;
+
+    msg!("POOL_MIGRATED{{\"token_mint_address\":\"{}\",\"reserve_sol\":{},\"reserve_token\":{}}}",
+        migrated.token,
+        migrated.reserve_sol,
+        migrated.reserve_token
+    );
+    Ok(())
+}
+
+// The current layout of a pool stored in the original layout as `legacy_data`. Its SOL reserve is
+// what its vault holds above the vault's rent-exempt minimum, the same amount `check_reserves`
+// holds trades to.
+pub fn migrated_pool(
+    legacy_data: &[u8],
+    graduation: GraduationSettings,
+    vault_lamports: u64,
+    rent: &Rent,
+    decimals: u8,
+) -> Result<LiquidityPool> {
+    let legacy = LegacyLiquidityPool::deserialize(&mut &legacy_data[8..])?;
+    let mut migrated = LiquidityPool::new(
+        legacy.creator,
+        legacy.token,
+        legacy.bump,
+        LEGACY_VIRTUAL_SOL_RESERVES,
+        LEGACY_PROPORTION,
+        LEGACY_INITIAL_LAMPORTS,
+        CurveKind::Quadratic,
+        LaunchFeeSchedule {
+            start_fee_bps: 0,
+            duration: 0,
+            clock: LaunchClock::Slots,
+            decay: LaunchDecay::Linear,
+        },
+        graduation,
+    );
+    migrated.total_supply = legacy.total_supply;
+    migrated.reserve_token = legacy.reserve_token;
+    migrated.reserve_sol = vault_lamports.saturating_sub(rent.minimum_balance(0));
+    migrated.last_price = migrated.spot_price(decimals)?;
+    Ok(migrated)
+}
+
+// Only accounts still in their original layout can be migrated
+fn check_legacy(account: &AccountInfo, discriminator: [u8; 8], legacy_size: usize) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if data.len() != legacy_size || data[..8] != discriminator {
+        return err!(CustomError::AlreadyMigrated);
+    }
+    Ok(())
+}
+
+// Reallocates `account` to `size` bytes, with `payer` topping it up to stay rent exempt
+fn grow<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(size, true)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateCurveConfiguration<'info> {
+    /// CHECK: still in the original layout, checked by `check_legacy`
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        space = ProtocolTreasury::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [ProtocolTreasury::SEED.as_bytes()],
+        bump,
+    )]
+    pub protocol_treasury: Box<Account<'info, ProtocolTreasury>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Only the program's upgrade authority may migrate the configuration
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AiAgent>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ CustomError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateLiquidityPool<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    /// CHECK: still in the original layout, checked by `check_legacy`
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK:
+    #[account(
+        seeds = [LiquidityPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        space = CreatorFeeVault::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [CreatorFeeVault::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fee_vault: Box<Account<'info, CreatorFeeVault>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -4,10 +4,58 @@ pub mod initialize;
 pub mod remove_liquidity;
 pub mod buy;
 pub mod sell;
+pub mod buy_exact_out;
+pub mod sell_exact_out;
+pub mod quote;
+pub mod sync_reserves;
+pub mod set_fee_recipient;
+pub mod claim_creator_fees;
+pub mod register_referrer;
+pub mod claim_referral_fees;
+pub mod withdraw_protocol_fees;
+pub mod set_pool_fee_override;
+pub mod set_fee_discount;
+pub mod set_holder_discount;
+pub mod update_config;
+pub mod transfer_admin;
+pub mod set_pause;
+pub mod config_timelock;
+pub mod fee_timelock;
+pub mod platform_authority;
+pub mod block_wallet;
+pub mod launch_token;
+pub mod graduate;
+pub mod amm_swap;
+pub mod amm_liquidity;
+pub mod migrate;
 
 pub use add_liquidity::*;
 pub use create_pool::*;
 pub use initialize::*;
 pub use remove_liquidity::*;
 pub use buy::*;
-pub use sell::*;
\ No newline at end of file
+pub use sell::*;
+pub use buy_exact_out::*;
+pub use sell_exact_out::*;
+pub use quote::*;
+pub use sync_reserves::*;
+pub use set_fee_recipient::*;
+pub use claim_creator_fees::*;
+pub use register_referrer::*;
+pub use claim_referral_fees::*;
+pub use withdraw_protocol_fees::*;
+pub use set_pool_fee_override::*;
+pub use set_fee_discount::*;
+pub use set_holder_discount::*;
+pub use update_config::*;
+pub use transfer_admin::*;
+pub use set_pause::*;
+pub use config_timelock::*;
+pub use fee_timelock::*;
+pub use platform_authority::*;
+pub use block_wallet::*;
+pub use launch_token::*;
+pub use graduate::*;
+pub use amm_swap::*;
+pub use amm_liquidity::*;
+pub use migrate::*;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, PlatformAuthority},
+};
+
+pub fn add_platform_authority(ctx: Context<AddPlatformAuthority>, authority: Pubkey) -> Result<()> {
+    ctx.accounts.platform_authority_entry.set_inner(PlatformAuthority {
+        authority,
+        bump: ctx.bumps.platform_authority_entry,
+    });
+
+    msg!("PLATFORM_AUTHORITY_ADDED{{\"authority\":\"{}\"}}", authority);
+    Ok(())
+}
+
+pub fn remove_platform_authority(ctx: Context<RemovePlatformAuthority>) -> Result<()> {
+    msg!("PLATFORM_AUTHORITY_REMOVED{{\"authority\":\"{}\"}}",
+        ctx.accounts.platform_authority_entry.authority
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddPlatformAuthority<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init,
+        space = PlatformAuthority::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [PlatformAuthority::SEED_PREFIX.as_bytes(), authority.as_ref()],
+        bump
+    )]
+    pub platform_authority_entry: Box<Account<'info, PlatformAuthority>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemovePlatformAuthority<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PlatformAuthority::SEED_PREFIX.as_bytes(), platform_authority_entry.authority.as_ref()],
+        bump = platform_authority_entry.bump
+    )]
+    pub platform_authority_entry: Box<Account<'info, PlatformAuthority>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::CustomError;
+use crate::state::{CurveConfiguration, LiquidityPool, PoolStatus, QuoteResult, TradeQuote};
+
+// Simulates a buy of `amount` lamports with the same maths as `buy`, without moving funds.
+// Quotes are for an undiscounted trader.
+pub fn quote_buy(ctx: Context<Quote>, amount: u64) -> Result<QuoteResult> {
+    let mut pool = (**ctx.accounts.pool).clone();
+    if !pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_buys() {
+        return err!(CustomError::Paused);
+    }
+    let fee_rate = pool.buy_fees(&ctx.accounts.dex_configuration_account.fees, 0, false)?.rate;
+
+    let quote = pool.quote_buy(amount, fee_rate)?;
+    pool.apply_buy(&quote)?;
+
+    quote_result(&pool, quote.sol_amount, quote.token_amount, &quote, ctx.accounts.token_mint.decimals)
+}
+
+// Simulates a sell of `amount` tokens with the same maths as `sell`, without moving funds.
+// Quotes are for an undiscounted trader.
+pub fn quote_sell(ctx: Context<Quote>, amount: u64) -> Result<QuoteResult> {
+    let mut pool = (**ctx.accounts.pool).clone();
+    if !pool.pause_mode(&ctx.accounts.dex_configuration_account).allows_sells() {
+        return err!(CustomError::Paused);
+    }
+    let fee_rate = pool.sell_fees(&ctx.accounts.dex_configuration_account.fees, 0)?.rate;
+
+    let quote = pool.quote_sell(amount, fee_rate)?;
+    pool.apply_sell(&quote)?;
+
+    quote_result(&pool, quote.token_amount, quote.sol_amount, &quote, ctx.accounts.token_mint.decimals)
+}
+
+fn quote_result(
+    pool: &LiquidityPool,
+    amount_in: u64,
+    amount_out: u64,
+    quote: &TradeQuote,
+    decimals: u8,
+) -> Result<QuoteResult> {
+    Ok(QuoteResult {
+        amount_in,
+        amount_out,
+        fee: quote.fee,
+        reserve_sol: pool.reserve_sol,
+        reserve_token: pool.reserve_token,
+        price_lamports_e9: pool.spot_price(decimals)?,
+    })
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Trading @ CustomError::PoolGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+}
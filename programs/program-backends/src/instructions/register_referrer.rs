@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Referrer;
+
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.set_inner(Referrer {
+        wallet: ctx.accounts.wallet.key(),
+        bump: ctx.bumps.referrer,
+        referred_volume: 0_u64,
+        lifetime_earnings: 0_u64,
+        claimed: 0_u64,
+    });
+
+    msg!("REFERRER_REGISTERED{{\"wallet\":\"{}\",\"referrer\":\"{}\"}}",
+        referrer.wallet,
+        referrer.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        space = Referrer::ACCOUNT_SIZE,
+        payer = wallet,
+        seeds = [Referrer::SEED_PREFIX.as_bytes(), wallet.key().as_ref()],
+        bump
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
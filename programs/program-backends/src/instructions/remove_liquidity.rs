@@ -3,7 +3,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
 };
-use crate::{ errors::CustomError, state::{LiquidityPool, LiquidityPoolAccount}};
+use crate::{ errors::CustomError, state::{LiquidityPool, LiquidityPoolAccount, PoolStatus}};
 
 pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, bump: u8) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
@@ -38,7 +38,8 @@ pub struct RemoveLiquidity<'info> {
     #[account(
         mut,
        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
-        bump = pool.bump
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Trading @ CustomError::PoolGraduated,
     )]
     pub pool: Box<Account<'info, LiquidityPool>>,
 
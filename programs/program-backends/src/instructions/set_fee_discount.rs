@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, FeeDiscount},
+};
+use curve_math::BPS_DENOMINATOR;
+
+// Grants `wallet` a fee discount of `discount_bps` until `expires_at`, or replaces its
+// existing one. A zero `discount_bps` revokes it.
+pub fn set_fee_discount(
+    ctx: Context<SetFeeDiscount>,
+    wallet: Pubkey,
+    discount_bps: u16,
+    expires_at: i64,
+) -> Result<()> {
+    check_discount_bps(discount_bps)?;
+
+    ctx.accounts.fee_discount.set_inner(FeeDiscount {
+        wallet,
+        discount_bps,
+        expires_at,
+        bump: ctx.bumps.fee_discount,
+    });
+    log_fee_discount(&ctx.accounts.fee_discount);
+    Ok(())
+}
+
+pub(crate) fn check_discount_bps(discount_bps: u16) -> Result<()> {
+    if discount_bps as u64 > BPS_DENOMINATOR {
+        return err!(CustomError::InvalidFeePercentage);
+    }
+    Ok(())
+}
+
+pub(crate) fn log_fee_discount(fee_discount: &FeeDiscount) {
+    msg!("FEE_DISCOUNT_UPDATED{{\"wallet\":\"{}\",\"discount_bps\":{},\"expires_at\":{}}}",
+        fee_discount.wallet,
+        fee_discount.discount_bps,
+        fee_discount.expires_at
+    );
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetFeeDiscount<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+        constraint = !dex_configuration_account.is_timelocked() @ CustomError::ConfigTimelocked,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        init_if_needed,
+        space = FeeDiscount::ACCOUNT_SIZE,
+        payer = admin,
+        seeds = [FeeDiscount::SEED_PREFIX.as_bytes(), wallet.as_ref()],
+        bump
+    )]
+    pub fee_discount: Box<Account<'info, FeeDiscount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
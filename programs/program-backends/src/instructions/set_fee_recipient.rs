@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CustomError, state::CurveConfiguration};
+
+pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, fee_recipient: Pubkey) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let previous = dex_config.fee_recipient;
+    dex_config.fee_recipient = fee_recipient;
+
+    msg!("FEE_RECIPIENT_UPDATED{{\"previous\":\"{}\",\"fee_recipient\":\"{}\"}}",
+        previous,
+        fee_recipient
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+        constraint = !dex_configuration_account.is_timelocked() @ CustomError::ConfigTimelocked,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, HolderDiscount},
+};
+
+pub fn set_holder_discount(ctx: Context<SetHolderDiscount>, holder_discount: HolderDiscount) -> Result<()> {
+    holder_discount.validate()?;
+    ctx.accounts.dex_configuration_account.holder_discount = holder_discount;
+
+    msg!("HOLDER_DISCOUNT_UPDATED{{\"platform_mint\":\"{}\",\"min_balance\":{},\"discount_bps\":{}}}",
+        holder_discount.platform_mint,
+        holder_discount.min_balance,
+        holder_discount.discount_bps
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHolderDiscount<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+        constraint = !dex_configuration_account.is_timelocked() @ CustomError::ConfigTimelocked,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
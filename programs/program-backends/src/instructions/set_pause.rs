@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, PauseMode},
+};
+
+// Pauses or resumes trading on every pool at once
+pub fn set_paused(ctx: Context<SetPaused>, mode: PauseMode) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    dex_config.paused = mode;
+
+    msg!("PAUSE_UPDATED{{\"scope\":\"global\",\"mode\":\"{:?}\"}}", mode);
+    Ok(())
+}
+
+// Pauses or resumes trading on a single pool. The global pause still applies on top of it.
+pub fn set_pool_paused(ctx: Context<SetPoolPaused>, mode: PauseMode) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.trading_paused = mode;
+
+    msg!("PAUSE_UPDATED{{\"scope\":\"pool\",\"token_mint_address\":\"{}\",\"mode\":\"{:?}\"}}",
+        ctx.accounts.token_mint.key(),
+        mode
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPoolPaused<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, FeeSettings, LiquidityPool, PoolFeeOverride},
+};
+
+// Sets or, with `None`, clears a pool's negotiated fees
+pub fn set_pool_fee_override(
+    ctx: Context<SetPoolFeeOverride>,
+    fee_override: Option<PoolFeeOverride>,
+) -> Result<()> {
+    if let Some(fee_override) = fee_override {
+        fee_override.validate()?;
+    }
+
+    apply_pool_fee_override(
+        &mut ctx.accounts.pool,
+        ctx.accounts.token_mint.key(),
+        &ctx.accounts.dex_configuration_account.fees,
+        fee_override,
+    );
+    Ok(())
+}
+
+// Shared with `execute_pool_fee_override`
+pub(crate) fn apply_pool_fee_override(
+    pool: &mut LiquidityPool,
+    token_mint: Pubkey,
+    settings: &FeeSettings,
+    fee_override: Option<PoolFeeOverride>,
+) {
+    let previous = pool.fee_override;
+    pool.fee_override = fee_override;
+
+    msg!("FEE_TIER_UPDATED{{\"token_mint_address\":\"{}\",\"previous_buy_fee_bps\":{},\"previous_sell_fee_bps\":{},\"buy_fee_bps\":{},\"sell_fee_bps\":{},\"override\":{}}}",
+        token_mint,
+        previous.map_or(settings.buy_fee_bps, |fees| fees.buy_fee_bps),
+        previous.map_or(settings.sell_fee_bps, |fees| fees.sell_fee_bps),
+        fee_override.map_or(settings.buy_fee_bps, |fees| fees.buy_fee_bps),
+        fee_override.map_or(settings.sell_fee_bps, |fees| fees.sell_fee_bps),
+        fee_override.is_some()
+    );
+}
+
+#[derive(Accounts)]
+pub struct SetPoolFeeOverride<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+        constraint = !dex_configuration_account.is_timelocked() @ CustomError::ConfigTimelocked,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    pub admin: Signer<'info>,
+}
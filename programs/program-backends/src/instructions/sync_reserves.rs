@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, LiquidityPool, LiquidityPoolAccount, PoolStatus},
+};
+
+pub fn sync_reserves(ctx: Context<SyncReserves>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let reserve_sol_before = pool.reserve_sol;
+    let reserve_token_before = pool.reserve_token;
+    let vault_lamports = ctx.accounts.pool_sol_vault.lamports();
+    let vault_tokens = ctx.accounts.pool_token_account.amount;
+
+    pool.update_reserves(vault_tokens, vault_lamports)?;
+    pool.last_price = pool.spot_price(ctx.accounts.token_mint.decimals)?;
+
+    msg!("RESERVES_SYNCED{{\"token_mint_address\":\"{}\",\"reserve_sol_before\":{},\"reserve_sol_after\":{},\"sol_discrepancy\":{},\"reserve_token_before\":{},\"reserve_token_after\":{},\"token_discrepancy\":{}}}",
+        ctx.accounts.token_mint.key(),
+        reserve_sol_before,
+        vault_lamports,
+        vault_lamports as i128 - reserve_sol_before as i128,
+        reserve_token_before,
+        vault_tokens,
+        vault_tokens as i128 - reserve_token_before as i128
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncReserves<'info> {
+    #[account(
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [LiquidityPool::POOL_SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.status == PoolStatus::Trading @ CustomError::PoolGraduated,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(
+        seeds = [LiquidityPool::SOL_VAULT_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: AccountInfo<'info>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CustomError, state::CurveConfiguration};
+
+// First step of an admin handover. The new admin has to accept before anything changes, and
+// proposing again replaces the pending admin.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    dex_config.pending_admin = Some(new_admin);
+
+    msg!("ADMIN_PROPOSED{{\"admin\":\"{}\",\"pending_admin\":\"{}\"}}",
+        dex_config.admin,
+        new_admin
+    );
+    Ok(())
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    let previous = dex_config.admin;
+    dex_config.admin = ctx.accounts.pending_admin.key();
+    dex_config.pending_admin = None;
+
+    msg!("ADMIN_TRANSFERRED{{\"previous_admin\":\"{}\",\"admin\":\"{}\"}}",
+        previous,
+        dex_config.admin
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        constraint = dex_configuration_account.pending_admin == Some(pending_admin.key()) @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub pending_admin: Signer<'info>,
+}
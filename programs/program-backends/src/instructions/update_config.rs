@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{ConfigUpdate, CurveConfiguration},
+};
+
+pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    update.validate()?;
+
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    dex_config.apply(&update);
+
+    msg!("CONFIG_UPDATED{{\"fees\":{},\"curve_limits\":{},\"fee_recipient\":{},\"config_change_delay\":{},\"graduation\":{},\"holder_discount\":{}}}",
+        update.fees.is_some(),
+        update.curve_limits.is_some(),
+        update.fee_recipient.is_some(),
+        update.config_change_delay.is_some(),
+        update.graduation.is_some(),
+        update.holder_discount.is_some()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+        constraint = !dex_configuration_account.is_timelocked() @ CustomError::ConfigTimelocked,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CustomError,
+    state::{CurveConfiguration, ProtocolTreasury},
+};
+
+// Moves `amount` lamports of accumulated protocol fees out of the treasury. The treasury always
+// keeps its rent-exempt minimum, and funds can only go to the configured fee recipient.
+pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(CustomError::InvalidAmount);
+    }
+
+    let treasury = ctx.accounts.protocol_treasury.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(treasury.data_len());
+    let available = treasury.lamports().saturating_sub(rent_exempt);
+    if amount > available {
+        return Err(error!(CustomError::InsufficientFunds).with_values((amount, available)));
+    }
+
+    **treasury.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+    let dex_config = &mut ctx.accounts.dex_configuration_account;
+    dex_config.protocol_fees_withdrawn = dex_config
+        .protocol_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+
+    msg!("PROTOCOL_FEES_WITHDRAWN{{\"destination\":\"{}\",\"amount\":{},\"total_collected\":{},\"total_withdrawn\":{}}}",
+        ctx.accounts.destination.key(),
+        amount,
+        dex_config.protocol_fees_collected,
+        dex_config.protocol_fees_withdrawn
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawProtocolFees<'info> {
+    #[account(
+        mut,
+        seeds = [CurveConfiguration::SEED.as_bytes()],
+        bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub dex_configuration_account: Box<Account<'info, CurveConfiguration>>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolTreasury::SEED.as_bytes()],
+        bump = protocol_treasury.bump
+    )]
+    pub protocol_treasury: Box<Account<'info, ProtocolTreasury>>,
+
+    /// CHECK:
+    #[account(mut, address = dex_configuration_account.fee_recipient @ CustomError::InvalidFeeRecipient)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
@@ -7,6 +7,11 @@ pub mod state;
 pub mod consts;
 
 use crate::instructions::*;
+use crate::state::{
+    ConfigUpdate, CurveLimits, FeeSettings, HolderDiscount, PauseMode, PoolFeeOverride,
+    QuoteResult,
+};
+use crate::utils::CurveKind;
 
 declare_id!("DHr5zADHP6mkJRZiZKoMnadQyqWKfq6kxXG7iZAcipNa");
 
@@ -14,12 +19,29 @@ declare_id!("DHr5zADHP6mkJRZiZKoMnadQyqWKfq6kxXG7iZAcipNa");
 pub mod ai_agent {
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeCurveConfiguration>, fee: f64) -> Result<()> {
-        instructions::initialize(ctx, fee)
+    pub fn initialize(
+        ctx: Context<InitializeCurveConfiguration>,
+        fees: FeeSettings,
+        curve_limits: CurveLimits,
+        fee_recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize(ctx, fees, curve_limits, fee_recipient)
     }
 
-    pub fn create_pool(ctx: Context<CreateLiquidityPool>) -> Result<()> {
-        instructions::create_pool(ctx)
+    pub fn create_pool(
+        ctx: Context<CreateLiquidityPool>,
+        virtual_sol_reserves: u64,
+        proportion: u64,
+        initial_lamports: u64,
+        curve_kind: CurveKind,
+    ) -> Result<()> {
+        instructions::create_pool(
+            ctx,
+            virtual_sol_reserves,
+            proportion,
+            initial_lamports,
+            curve_kind,
+        )
     }
 
     pub fn add_liquidity(
@@ -32,12 +54,225 @@ pub mod ai_agent {
         instructions::remove_liquidity(ctx, bump)
     }
 
-    pub fn buy(ctx: Context<Buy>, amount: u64) -> Result<()> {
-        instructions::buy(ctx, amount)
+    pub fn buy(ctx: Context<Buy>, amount: u64, min_tokens_out: u64) -> Result<()> {
+        instructions::buy(ctx, amount, min_tokens_out)
+    }
+
+    pub fn sell(ctx: Context<Sell>, amount: u64, bump: u8, min_sol_out: u64) -> Result<()> {
+        instructions::sell(ctx, amount, bump, min_sol_out)
+    }
+
+    pub fn buy_exact_out(ctx: Context<Buy>, token_amount: u64, max_sol_in: u64) -> Result<()> {
+        instructions::buy_exact_out(ctx, token_amount, max_sol_in)
+    }
+
+    pub fn sell_exact_out(
+        ctx: Context<Sell>,
+        sol_amount: u64,
+        max_tokens_in: u64,
+        bump: u8,
+    ) -> Result<()> {
+        instructions::sell_exact_out(ctx, sol_amount, max_tokens_in, bump)
+    }
+
+    pub fn quote_buy(ctx: Context<Quote>, amount: u64) -> Result<QuoteResult> {
+        instructions::quote_buy(ctx, amount)
+    }
+
+    pub fn quote_sell(ctx: Context<Quote>, amount: u64) -> Result<QuoteResult> {
+        instructions::quote_sell(ctx, amount)
+    }
+
+    pub fn sync_reserves(ctx: Context<SyncReserves>) -> Result<()> {
+        instructions::sync_reserves(ctx)
+    }
+
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, fee_recipient: Pubkey) -> Result<()> {
+        instructions::set_fee_recipient(ctx, fee_recipient)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees(ctx)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees(ctx)
+    }
+
+    pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_protocol_fees(ctx, amount)
+    }
+
+    pub fn set_pool_fee_override(
+        ctx: Context<SetPoolFeeOverride>,
+        fee_override: Option<PoolFeeOverride>,
+    ) -> Result<()> {
+        instructions::set_pool_fee_override(ctx, fee_override)
+    }
+
+    pub fn set_fee_discount(
+        ctx: Context<SetFeeDiscount>,
+        wallet: Pubkey,
+        discount_bps: u16,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::set_fee_discount(ctx, wallet, discount_bps, expires_at)
+    }
+
+    pub fn set_holder_discount(
+        ctx: Context<SetHolderDiscount>,
+        holder_discount: HolderDiscount,
+    ) -> Result<()> {
+        instructions::set_holder_discount(ctx, holder_discount)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+        instructions::update_config(ctx, update)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, mode: PauseMode) -> Result<()> {
+        instructions::set_paused(ctx, mode)
+    }
+
+    pub fn set_pool_paused(ctx: Context<SetPoolPaused>, mode: PauseMode) -> Result<()> {
+        instructions::set_pool_paused(ctx, mode)
+    }
+
+    pub fn queue_config_change(ctx: Context<QueueConfigChange>, update: ConfigUpdate) -> Result<()> {
+        instructions::queue_config_change(ctx, update)
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change(ctx)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change(ctx)
+    }
+
+    pub fn queue_pool_fee_override(
+        ctx: Context<QueuePoolFeeOverride>,
+        fee_override: Option<PoolFeeOverride>,
+    ) -> Result<()> {
+        instructions::queue_pool_fee_override(ctx, fee_override)
+    }
+
+    pub fn execute_pool_fee_override(ctx: Context<ExecutePoolFeeOverride>) -> Result<()> {
+        instructions::execute_pool_fee_override(ctx)
+    }
+
+    pub fn cancel_pool_fee_override(ctx: Context<CancelPoolFeeOverride>) -> Result<()> {
+        instructions::cancel_pool_fee_override(ctx)
+    }
+
+    pub fn queue_fee_discount(
+        ctx: Context<QueueFeeDiscount>,
+        wallet: Pubkey,
+        discount_bps: u16,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::queue_fee_discount(ctx, wallet, discount_bps, expires_at)
+    }
+
+    pub fn execute_fee_discount(ctx: Context<ExecuteFeeDiscount>) -> Result<()> {
+        instructions::execute_fee_discount(ctx)
+    }
+
+    pub fn cancel_fee_discount(ctx: Context<CancelFeeDiscount>) -> Result<()> {
+        instructions::cancel_fee_discount(ctx)
+    }
+
+    pub fn add_platform_authority(ctx: Context<AddPlatformAuthority>, authority: Pubkey) -> Result<()> {
+        instructions::add_platform_authority(ctx, authority)
+    }
+
+    pub fn remove_platform_authority(ctx: Context<RemovePlatformAuthority>) -> Result<()> {
+        instructions::remove_platform_authority(ctx)
+    }
+
+    pub fn block_wallet(ctx: Context<BlockWallet>, wallet: Pubkey, allow_sells: bool) -> Result<()> {
+        instructions::block_wallet(ctx, wallet, allow_sells)
+    }
+
+    pub fn unblock_wallet(ctx: Context<UnblockWallet>) -> Result<()> {
+        instructions::unblock_wallet(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch_token(
+        ctx: Context<LaunchToken>,
+        name: String,
+        symbol: String,
+        uri: String,
+        virtual_sol_reserves: u64,
+        proportion: u64,
+        initial_lamports: u64,
+        curve_kind: CurveKind,
+        initial_buy: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::launch_token(
+            ctx,
+            name,
+            symbol,
+            uri,
+            virtual_sol_reserves,
+            proportion,
+            initial_lamports,
+            curve_kind,
+            initial_buy,
+            min_tokens_out,
+        )
+    }
+
+    pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+        instructions::graduate(ctx)
+    }
+
+    pub fn amm_buy(ctx: Context<AmmSwap>, sol_in: u64, min_tokens_out: u64) -> Result<()> {
+        instructions::amm_buy(ctx, sol_in, min_tokens_out)
+    }
+
+    pub fn amm_sell(ctx: Context<AmmSwap>, tokens_in: u64, min_sol_out: u64) -> Result<()> {
+        instructions::amm_sell(ctx, tokens_in, min_sol_out)
+    }
+
+    pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, sol_amount: u64, max_token_amount: u64) -> Result<()> {
+        instructions::deposit_liquidity(ctx, sol_amount, max_token_amount)
+    }
+
+    pub fn withdraw_liquidity(
+        ctx: Context<WithdrawLiquidity>,
+        shares: u64,
+        min_sol_out: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::withdraw_liquidity(ctx, shares, min_sol_out, min_tokens_out)
+    }
+
+    pub fn migrate_config(
+        ctx: Context<MigrateCurveConfiguration>,
+        fees: FeeSettings,
+        curve_limits: CurveLimits,
+        fee_recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate_config(ctx, fees, curve_limits, fee_recipient)
     }
 
-    pub fn sell(ctx: Context<Sell>, amount: u64, bump: u8) -> Result<()> {
-        instructions::sell(ctx, amount, bump)
+    pub fn migrate_pool(ctx: Context<MigrateLiquidityPool>) -> Result<()> {
+        instructions::migrate_pool(ctx)
     }
     
 }
@@ -1,42 +1,498 @@
-use crate::consts::INITIAL_LAMPORTS_FOR_POOL;
-use crate::consts::PROPORTION;
 use crate::errors::CustomError;
-use anchor_lang::{
-    prelude::*,
-    solana_program::{
-        program::invoke,
-        system_instruction::transfer,
-    },
-};
+use crate::utils::{CurveKind, LaunchFeeSchedule};
+use curve_math::{bps_of, AmmState, PoolState, BPS_DENOMINATOR};
+pub use curve_math::{FeeRate, TradeQuote, PRICE_SCALE};
+use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount};
 
 #[account]
 pub struct CurveConfiguration {
-    pub fees: f64,
+    pub fees: FeeSettings,
+    pub curve_limits: CurveLimits,
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub protocol_fees_collected: u64,
+    pub protocol_fees_withdrawn: u64,
+    pub holder_discount: HolderDiscount,
+    pub pending_admin: Option<Pubkey>,
+    pub paused: PauseMode,
+    pub config_change_delay: i64,
+    pub graduation: GraduationSettings,
 }
 
 impl CurveConfiguration {
     pub const SEED: &'static str = "CurveConfiguration";
 
-    // Discriminator (8) + f64 (8)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8;
+    // Discriminator (8) + FeeSettings (28) + CurveLimits (48) + admin (32) + fee recipient (32)
+    // + protocol fees collected (8) + protocol fees withdrawn (8) + HolderDiscount (42)
+    // + pending admin (1 + 32) + pause mode (1) + config change delay (8) + GraduationSettings (11)
+    pub const ACCOUNT_SIZE: usize = 8 + FeeSettings::SIZE + CurveLimits::SIZE + 32 + 32 + 8 + 8
+        + HolderDiscount::SIZE + 1 + 32 + 1 + 8 + GraduationSettings::SIZE;
 
-     pub fn new(fees: f64) -> Self {
-        Self { fees }
+    pub fn new(fees: FeeSettings, curve_limits: CurveLimits, admin: Pubkey, fee_recipient: Pubkey) -> Self {
+        Self {
+            fees,
+            curve_limits,
+            admin,
+            fee_recipient,
+            protocol_fees_collected: 0_u64,
+            protocol_fees_withdrawn: 0_u64,
+            holder_discount: HolderDiscount::default(),
+            pending_admin: None,
+            paused: PauseMode::Unpaused,
+            config_change_delay: 0_i64,
+            graduation: GraduationSettings::default(),
+        }
+    }
+
+    pub fn apply(&mut self, update: &ConfigUpdate) {
+        if let Some(fees) = update.fees {
+            self.fees = fees;
+        }
+        if let Some(curve_limits) = update.curve_limits {
+            self.curve_limits = curve_limits;
+        }
+        if let Some(fee_recipient) = update.fee_recipient {
+            self.fee_recipient = fee_recipient;
+        }
+        if let Some(config_change_delay) = update.config_change_delay {
+            self.config_change_delay = config_change_delay;
+        }
+        if let Some(graduation) = update.graduation {
+            self.graduation = graduation;
+        }
+        if let Some(holder_discount) = update.holder_discount {
+            self.holder_discount = holder_discount;
+        }
+    }
+
+    // Once a delay is set, changes have to be queued instead of applied directly: settings with
+    // `queue_config_change`, pool fee overrides with `queue_pool_fee_override` and wallet fee
+    // discounts with `queue_fee_discount`.
+    pub fn is_timelocked(&self) -> bool {
+        self.config_change_delay > 0
+    }
+
+    // When a change queued at `queued_at` can be executed
+    pub fn change_executable_at(&self, queued_at: i64) -> Result<i64> {
+        Ok(queued_at
+            .checked_add(self.config_change_delay)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?)
+    }
+
+    pub fn record_protocol_fee(&mut self, fee: u64) -> Result<()> {
+        self.protocol_fees_collected = self
+            .protocol_fees_collected
+            .checked_add(fee)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        Ok(())
+    }
+
+    // Fee discount in bps for a trader: the larger of their `FeeDiscount`, while it has not
+    // expired, and the platform token holder discount
+    pub fn discount_bps(
+        &self,
+        fee_discount: Option<&FeeDiscount>,
+        platform_token_account: Option<&TokenAccount>,
+    ) -> Result<u16> {
+        let now = Clock::get()?.unix_timestamp;
+        let wallet_discount = fee_discount
+            .filter(|discount| discount.expires_at == 0 || now < discount.expires_at)
+            .map_or(0, |discount| discount.discount_bps);
+        let holder_discount = platform_token_account
+            .filter(|account| self.holder_discount.applies(account))
+            .map_or(0, |_| self.holder_discount.discount_bps);
+
+        Ok(wallet_discount.max(holder_discount))
     }
 }
 
+// How far trading is halted, either for every pool through `CurveConfiguration::paused` or for
+// one pool through `LiquidityPool::trading_paused`. Variants are ordered from least to most
+// restrictive so the stricter of the two can be taken with `max`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PauseMode {
+    Unpaused,
+    // Holders can still sell out of a pool but nothing can be bought or added
+    SellsOnly,
+    All,
+}
+
+impl PauseMode {
+    pub fn allows_buys(self) -> bool {
+        self == PauseMode::Unpaused
+    }
+
+    pub fn allows_sells(self) -> bool {
+        self != PauseMode::All
+    }
+}
+
+// Settings changed by `update_config` or a queued config change. Fields left as `None` keep
+// their current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ConfigUpdate {
+    pub fees: Option<FeeSettings>,
+    pub curve_limits: Option<CurveLimits>,
+    pub fee_recipient: Option<Pubkey>,
+    // Seconds a queued change waits before it can be executed
+    pub config_change_delay: Option<i64>,
+    pub graduation: Option<GraduationSettings>,
+    pub holder_discount: Option<HolderDiscount>,
+}
+
+impl ConfigUpdate {
+    pub const SIZE: usize = 1 + FeeSettings::SIZE + 1 + CurveLimits::SIZE + 1 + 32 + 1 + 8
+        + 1 + GraduationSettings::SIZE + 1 + HolderDiscount::SIZE;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.config_change_delay.is_some_and(|delay| delay < 0) {
+            return err!(CustomError::InvalidConfigChangeDelay);
+        }
+        if let Some(fees) = self.fees {
+            fees.validate()?;
+        }
+        if let Some(curve_limits) = self.curve_limits {
+            curve_limits.validate()?;
+        }
+        if let Some(graduation) = self.graduation {
+            graduation.validate()?;
+        }
+        if let Some(holder_discount) = self.holder_discount {
+            holder_discount.validate()?;
+        }
+        Ok(())
+    }
+}
+
+// A `ConfigUpdate` waiting out the configuration's timelock. There is at most one at a time.
+#[account]
+pub struct PendingConfigChange {
+    pub update: ConfigUpdate,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub const SEED: &'static str = "pending_config_change";
+
+    // Discriminator (8) + ConfigUpdate (175) + queued at (8) + executable at (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + ConfigUpdate::SIZE + 8 + 8 + 1;
+}
+
+// A pool fee override, or with `None` its removal, waiting out the configuration's timelock.
+// There is at most one per pool at a time.
+#[account]
+pub struct PendingPoolFeeOverride {
+    pub fee_override: Option<PoolFeeOverride>,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingPoolFeeOverride {
+    pub const SEED_PREFIX: &'static str = "pending_pool_fee_override";
+
+    // Discriminator (8) + Option<PoolFeeOverride> (1 + 4) + queued at (8) + executable at (8)
+    // + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 1 + PoolFeeOverride::SIZE + 8 + 8 + 1;
+}
+
+// A wallet's `FeeDiscount` waiting out the configuration's timelock. There is at most one per
+// wallet at a time.
+#[account]
+pub struct PendingFeeDiscount {
+    pub wallet: Pubkey,
+    pub discount_bps: u16,
+    pub expires_at: i64,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingFeeDiscount {
+    pub const SEED_PREFIX: &'static str = "pending_fee_discount";
+
+    // Discriminator (8) + Pubkey (32) + discount (2) + expiry (8) + queued at (8)
+    // + executable at (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 2 + 8 + 8 + 8 + 1;
+}
+
+// Marks `authority` as a platform wallet allowed to seed pools through `add_liquidity`. Exists
+// only while the authority is allowed.
+#[account]
+pub struct PlatformAuthority {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+impl PlatformAuthority {
+    pub const SEED_PREFIX: &'static str = "platform_authority";
+
+    // Discriminator (8) + authority (32) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 1;
+}
+
+// A wallet the admin has blocked from trading. Exists only while the block is in place.
+#[account]
+pub struct BlockedWallet {
+    pub wallet: Pubkey,
+    // Lets the wallet still sell out of its positions
+    pub allow_sells: bool,
+    pub blocked_at: i64,
+    pub bump: u8,
+}
+
+impl BlockedWallet {
+    pub const SEED_PREFIX: &'static str = "blocked_wallet";
+
+    // Discriminator (8) + wallet (32) + allow sells (1) + blocked at (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 1 + 8 + 1;
+
+    // Whether the wallet owning the `BlockedWallet` address `info` may trade. The address has
+    // to be passed on every trade so that a block can't be dodged by leaving it out; while
+    // nothing is stored there the wallet isn't blocked.
+    pub fn allows_trade(info: &AccountInfo, selling: bool) -> Result<bool> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(true);
+        }
+        let blocked = BlockedWallet::try_deserialize(&mut &info.data.borrow()[..])?;
+        Ok(selling && blocked.allow_sells)
+    }
+}
+
+// Discount for wallets holding at least `min_balance` of the platform token. A default
+// `platform_mint` turns it off.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct HolderDiscount {
+    pub platform_mint: Pubkey,
+    pub min_balance: u64,
+    pub discount_bps: u16,
+}
+
+impl HolderDiscount {
+    pub const SIZE: usize = 32 + 8 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.discount_bps as u64 > BPS_DENOMINATOR {
+            return err!(CustomError::InvalidFeePercentage);
+        }
+        Ok(())
+    }
+
+    pub fn applies(&self, platform_token_account: &TokenAccount) -> bool {
+        self.platform_mint != Pubkey::default()
+            && platform_token_account.mint == self.platform_mint
+            && platform_token_account.amount >= self.min_balance
+    }
+}
+
+// Admin-granted fee discount for one wallet, e.g. a market maker. `expires_at` is a unix
+// timestamp, or zero for a discount that does not expire.
+#[account]
+pub struct FeeDiscount {
+    pub wallet: Pubkey,
+    pub discount_bps: u16,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl FeeDiscount {
+    pub const SEED_PREFIX: &'static str = "fee_discount";
+
+    // Discriminator (8) + Pubkey (32) + discount (2) + expiry (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 2 + 8 + 1;
+}
+
+// Program-owned account the protocol's share of trade fees accumulates in until an admin
+// withdraws it with `withdraw_protocol_fees`
+#[account]
+pub struct ProtocolTreasury {
+    pub bump: u8,
+}
+
+impl ProtocolTreasury {
+    pub const SEED: &'static str = "protocol_treasury";
+
+    // Discriminator (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 1;
+}
+
+// Trade fees and how the collected fee is shared out. Creator and referral shares are
+// basis points of the fee itself, not of the trade.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct FeeSettings {
+    pub buy_fee_bps: u16,
+    pub sell_fee_bps: u16,
+    pub min_fee_lamports: u64,
+    pub creator_fee_share_bps: u16,
+    pub referral_fee_share_bps: u16,
+    pub launch_fee: LaunchFeeSchedule,
+}
+
+impl FeeSettings {
+    pub const SIZE: usize = 2 + 2 + 8 + 2 + 2 + LaunchFeeSchedule::SIZE;
+
+    pub fn buy_fees(&self) -> TradeFees {
+        TradeFees {
+            rate: FeeRate { bps: self.buy_fee_bps, min_lamports: self.min_fee_lamports },
+            creator_share_bps: self.creator_fee_share_bps,
+            referral_share_bps: self.referral_fee_share_bps,
+        }
+    }
+
+    pub fn sell_fees(&self) -> TradeFees {
+        TradeFees {
+            rate: FeeRate { bps: self.sell_fee_bps, min_lamports: self.min_fee_lamports },
+            creator_share_bps: self.creator_fee_share_bps,
+            referral_share_bps: self.referral_fee_share_bps,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        self.buy_fees().rate.validate().map_err(CustomError::from)?;
+        self.sell_fees().rate.validate().map_err(CustomError::from)?;
+        if self.creator_fee_share_bps as u64 + self.referral_fee_share_bps as u64 > BPS_DENOMINATOR {
+            return err!(CustomError::InvalidFeePercentage);
+        }
+        self.launch_fee.validate()?;
+        Ok(())
+    }
+}
+
+// The fee charged on one side of a trade and how it is shared out once collected
+#[derive(Clone, Copy, Debug)]
+pub struct TradeFees {
+    pub rate: FeeRate,
+    pub creator_share_bps: u16,
+    pub referral_share_bps: u16,
+}
+
+// Where a collected fee goes. The protocol keeps whatever the shares leave over.
+#[derive(Clone, Copy, Debug)]
+pub struct FeeSplit {
+    pub protocol: u64,
+    pub creator: u64,
+    pub referral: u64,
+}
+
+impl TradeFees {
+    // Splits a collected fee into its portions. The referral share is only paid out when the
+    // trade came through a referrer and otherwise stays with the protocol.
+    pub fn split(&self, fee: u64, referred: bool) -> Result<FeeSplit> {
+        let creator = bps_of(fee, self.creator_share_bps).map_err(CustomError::from)?;
+        let referral = if referred {
+            bps_of(fee, self.referral_share_bps).map_err(CustomError::from)?
+        } else {
+            0
+        };
+        let protocol = fee
+            .checked_sub(creator)
+            .and_then(|rest| rest.checked_sub(referral))
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        Ok(FeeSplit { protocol, creator, referral })
+    }
+}
+
+// Bounds a new pool's curve parameters must fall within, all inclusive
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CurveLimits {
+    pub min_virtual_sol_reserves: u64,
+    pub max_virtual_sol_reserves: u64,
+    pub min_proportion: u64,
+    pub max_proportion: u64,
+    pub min_initial_lamports: u64,
+    pub max_initial_lamports: u64,
+}
+
+impl CurveLimits {
+    pub const SIZE: usize = 8 * 6;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.min_virtual_sol_reserves == 0
+            || self.min_virtual_sol_reserves > self.max_virtual_sol_reserves
+            || self.min_proportion == 0
+            || self.min_proportion > self.max_proportion
+            || self.min_initial_lamports > self.max_initial_lamports
+        {
+            return err!(CustomError::InvalidCurveParameters);
+        }
+        Ok(())
+    }
+
+    pub fn check(&self, virtual_sol_reserves: u64, proportion: u64, initial_lamports: u64) -> Result<()> {
+        if !(self.min_virtual_sol_reserves..=self.max_virtual_sol_reserves).contains(&virtual_sol_reserves)
+            || !(self.min_proportion..=self.max_proportion).contains(&proportion)
+            || !(self.min_initial_lamports..=self.max_initial_lamports).contains(&initial_lamports)
+        {
+            return err!(CustomError::InvalidCurveParameters);
+        }
+        Ok(())
+    }
+}
+
+// A wallet's share of a graduated token's `AmmPool`
 #[account]
 pub struct LiquidityProvider {
-    pub shares: u64, 
+    pub owner: Pubkey,
+    pub amm_pool: Pubkey,
+    pub shares: u64,
+    pub bump: u8,
 }
 
 impl LiquidityProvider {
-    pub const SEED_PREFIX: &'static str = "LiqudityProvider"; // Prefix for generating PDAs
+    pub const SEED_PREFIX: &'static str = "LiquidityProvider"; // Prefix for generating PDAs
 
-    // Discriminator (8) + f64 (8)
-    pub const ACCOUNT_SIZE: usize = 8 + 8;
+    // Discriminator (8) + owner (32) + amm pool (32) + shares (8) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 1;
+}
+
+// A wallet that refers trades, e.g. through shared blinks. Its referral fees are paid into this
+// account and `claim_referral_fees` pays them out to `wallet`.
+#[account]
+pub struct Referrer {
+    pub wallet: Pubkey,
+    pub bump: u8,
+    pub referred_volume: u64,
+    pub lifetime_earnings: u64,
+    pub claimed: u64,
+}
+
+impl Referrer {
+    pub const SEED_PREFIX: &'static str = "referrer";
+
+    // Discriminator (8) + Pubkey (32) + Bump (1) + referred volume (8)
+    // + lifetime earnings (8) + claimed (8)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 1 + 8 + 8 + 8;
+
+    // Records a referred trade of `volume` lamports that paid this referrer `earnings`
+    pub fn record(&mut self, volume: u64, earnings: u64) -> Result<()> {
+        self.referred_volume = self
+            .referred_volume
+            .checked_add(volume)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        self.lifetime_earnings = self
+            .lifetime_earnings
+            .checked_add(earnings)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        Ok(())
+    }
+}
+
+// Holds a pool's creator fees until `claim_creator_fees` pays them out to the creator
+#[account]
+pub struct CreatorFeeVault {
+    pub pool: Pubkey,
+    pub bump: u8,
+}
+
+impl CreatorFeeVault {
+    pub const SEED_PREFIX: &'static str = "creator_fee_vault";
+
+    // Discriminator (8) + Pubkey (32) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 1;
 }
 
 #[account]
@@ -47,29 +503,432 @@ pub struct LiquidityPool {
     pub reserve_token: u64, 
     pub reserve_sol: u64,   
     pub bump: u8,           
+    pub virtual_sol_reserves: u64,
+    pub proportion: u64,
+    pub initial_lamports: u64,
+    pub curve_kind: CurveKind,
+    // Spot price after the last trade, in lamports per whole token times `PRICE_SCALE`
+    pub last_price: u64,
+    pub creator_fees_accrued: u64,
+    pub creator_fees_claimed: u64,
+    pub launch_fee: LaunchFeeSchedule,
+    pub launch_slot: u64,
+    pub launch_timestamp: i64,
+    pub fee_override: Option<PoolFeeOverride>,
+    pub trading_paused: PauseMode,
+    pub graduation: GraduationSettings,
+    pub status: PoolStatus,
+}
+
+// What a pool's graduation threshold is measured against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GraduationMetric {
+    #[default]
+    ReserveSol,
+    MarketCap,
+}
+
+// When a pool leaves its bonding curve for a constant-product `AmmPool`, and the swap fee of
+// that pool. A zero `threshold` means the pool never graduates.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct GraduationSettings {
+    pub metric: GraduationMetric,
+    pub threshold: u64,
+    pub amm_fee_bps: u16,
+}
+
+impl GraduationSettings {
+    pub const SIZE: usize = 1 + 8 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.amm_fee_bps as u64 >= BPS_DENOMINATOR {
+            return err!(CustomError::InvalidFeePercentage);
+        }
+        Ok(())
+    }
+
+    pub fn reached(&self, pool: &LiquidityPool) -> Result<bool> {
+        if self.threshold == 0 {
+            return Ok(false);
+        }
+        let value = match self.metric {
+            GraduationMetric::ReserveSol => pool.reserve_sol,
+            GraduationMetric::MarketCap => pool.market_cap()?,
+        };
+        Ok(value >= self.threshold)
+    }
 }
 
-impl LiquidityPool {
-    pub const POOL_SEED_PREFIX: &'static str = "liquidity_pool";
-    pub const SOL_VAULT_PREFIX: &'static str = "liquidity_sol_vault";
+// Where a pool is in its life. Curve trading stops once it is `Graduated`, and `graduate`
+// moves its reserves into an `AmmPool` and marks it `Migrated`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolStatus {
+    Trading,
+    Graduated,
+    Migrated,
+}
+
+// Constant-product pool a token trades in after graduating from its bonding curve. The
+// reserves it opens with are matched by `locked_lp` shares that no one can withdraw.
+#[account]
+pub struct AmmPool {
+    pub token: Pubkey,
+    pub reserve_token: u64,
+    pub reserve_sol: u64,
+    pub lp_supply: u64,
+    pub locked_lp: u64,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl AmmPool {
+    pub const SEED_PREFIX: &'static str = "amm_pool";
+    pub const SOL_VAULT_PREFIX: &'static str = "amm_sol_vault";
+
+    // Discriminator (8) + token (32) + reserve token (8) + reserve sol (8) + lp supply (8)
+    // + locked lp (8) + fee (2) + Bump (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 2 + 1;
+
+    pub fn amm_state(&self) -> AmmState {
+        AmmState {
+            reserve_token: self.reserve_token,
+            reserve_sol: self.reserve_sol,
+            lp_supply: self.lp_supply,
+            fee_bps: self.fee_bps,
+        }
+    }
+
+    pub fn spot_price(&self, decimals: u8) -> Result<u64> {
+        Ok(self.amm_state().spot_price(decimals).map_err(CustomError::from)?)
+    }
+}
+
+pub trait AmmPoolAccount<'info> {
+    fn transfer_token_from_pool(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        amount: u64,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()>;
+
+    fn transfer_token_to_pool(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        amount: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()>;
+
+    fn transfer_sol_from_pool(
+        &self,
+        from: &AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bump: u8,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
+    fn transfer_sol_to_pool(
+        &self,
+        from: &Signer<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+}
+
+impl<'info> AmmPoolAccount<'info> for Account<'info, AmmPool> {
+    fn transfer_token_from_pool(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        amount: u64,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()> {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.to_account_info(),
+                },
+                &[&[
+                    AmmPool::SEED_PREFIX.as_bytes(),
+                    self.token.as_ref(),
+                    &[self.bump],
+                ]],
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_token_to_pool(
+        &self,
+        from: &Account<'info, TokenAccount>,
+        to: &Account<'info, TokenAccount>,
+        amount: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: authority.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_sol_from_pool(
+        &self,
+        from: &AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bump: u8,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: from.clone(),
+                    to,
+                },
+                &[&[
+                    AmmPool::SOL_VAULT_PREFIX.as_bytes(),
+                    self.token.as_ref(),
+                    &[bump],
+                ]],
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_sol_to_pool(
+        &self,
+        from: &Signer<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: from.to_account_info(),
+                    to: to.clone(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+// Negotiated buy and sell fees for one pool, replacing the global ones in `CurveConfiguration`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolFeeOverride {
+    pub buy_fee_bps: u16,
+    pub sell_fee_bps: u16,
+}
+
+impl PoolFeeOverride {
+    pub const SIZE: usize = 2 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.buy_fee_bps as u64 > BPS_DENOMINATOR || self.sell_fee_bps as u64 > BPS_DENOMINATOR {
+            return err!(CustomError::InvalidFeePercentage);
+        }
+        Ok(())
+    }
+}
+
+impl LiquidityPool {
+    pub const POOL_SEED_PREFIX: &'static str = "liquidity_pool";
+    pub const SOL_VAULT_PREFIX: &'static str = "liquidity_sol_vault";
+
+    // Every launched token has the same fixed supply: a billion whole tokens of 9 decimals
+    pub const TOKEN_DECIMALS: u8 = 9;
+    pub const TOKEN_SUPPLY: u64 = 1_000_000_000_000_000_000;
+
+    // Discriminator (8) + Pubkey (32) + Pubkey (32) + totalsupply (8)
+    // + reserve one (8) + reserve two (8) + Bump (1) + virtual sol (8)
+    // + proportion (8) + initial lamports (8) + curve kind (1) + last price (8)
+    // + creator fees accrued (8) + creator fees claimed (8) + LaunchFeeSchedule (12)
+    // + launch slot (8) + launch timestamp (8) + Option<PoolFeeOverride> (1 + 4) + pause mode (1)
+    // + GraduationSettings (11) + status (1)
+    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8
+        + LaunchFeeSchedule::SIZE + 8 + 8 + 1 + PoolFeeOverride::SIZE + 1 + GraduationSettings::SIZE + 1;
+
+    // Constructor to initialize a LiquidityPool with two tokens, a bump for the PDA and its curve parameters
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        creator: Pubkey,
+        token: Pubkey,
+        bump: u8,
+        virtual_sol_reserves: u64,
+        proportion: u64,
+        initial_lamports: u64,
+        curve_kind: CurveKind,
+        launch_fee: LaunchFeeSchedule,
+        graduation: GraduationSettings,
+    ) -> Self {
+        Self {
+            creator,
+            token,
+            total_supply: 0_u64,
+            reserve_token: 0_u64,
+            reserve_sol: 0_u64,
+            bump,
+            virtual_sol_reserves,
+            proportion,
+            initial_lamports,
+            curve_kind,
+            last_price: 0_u64,
+            creator_fees_accrued: 0_u64,
+            creator_fees_claimed: 0_u64,
+            launch_fee,
+            launch_slot: 0_u64,
+            launch_timestamp: 0_i64,
+            fee_override: None,
+            trading_paused: PauseMode::Unpaused,
+            graduation,
+            status: PoolStatus::Trading,
+        }
+    }
+
+    // Records the reserves a pool was seeded with and starts its launch window
+    fn open_trading(&mut self, reserve_token: u64, decimals: u8) -> Result<()> {
+        self.total_supply = Self::TOKEN_SUPPLY;
+        self.reserve_token = reserve_token;
+        self.reserve_sol = self.initial_lamports;
+        self.last_price = self.spot_price(decimals)?;
+
+        let clock = Clock::get()?;
+        self.launch_slot = clock.slot;
+        self.launch_timestamp = clock.unix_timestamp;
+        Ok(())
+    }
+
+    // The stricter of the global pause and this pool's own
+    pub fn pause_mode(&self, dex_config: &CurveConfiguration) -> PauseMode {
+        dex_config.paused.max(self.trading_paused)
+    }
+
+    // Fees for a buy on this pool: its override if it has one or else the global buy fee, less
+    // the trader's discount, then raised by the launch schedule while the pool is still in its
+    // launch window. Discounts don't cut into the launch fee. `skip_launch_fee` leaves the
+    // schedule out, for the creator's buy in the same transaction as `launch_token`.
+    pub fn buy_fees(&self, settings: &FeeSettings, discount_bps: u16, skip_launch_fee: bool) -> Result<TradeFees> {
+        let mut fees = settings.buy_fees();
+        if let Some(fee_override) = self.fee_override {
+            fees.rate.bps = fee_override.buy_fee_bps;
+        }
+        self.adjust_fees(fees, discount_bps, skip_launch_fee)
+    }
+
+    // Same as `buy_fees`, for a sell
+    pub fn sell_fees(&self, settings: &FeeSettings, discount_bps: u16) -> Result<TradeFees> {
+        let mut fees = settings.sell_fees();
+        if let Some(fee_override) = self.fee_override {
+            fees.rate.bps = fee_override.sell_fee_bps;
+        }
+        self.adjust_fees(fees, discount_bps, false)
+    }
+
+    fn adjust_fees(&self, mut fees: TradeFees, discount_bps: u16, skip_launch_fee: bool) -> Result<TradeFees> {
+        let discount = bps_of(fees.rate.bps as u64, discount_bps).map_err(CustomError::from)?;
+        fees.rate.bps -= discount as u16;
+        if !skip_launch_fee {
+            fees.rate.bps = self
+                .launch_fee
+                .fee_bps(fees.rate.bps, self.launch_slot, self.launch_timestamp)?;
+        }
+        Ok(fees)
+    }
+
+    pub fn curve_state(&self) -> PoolState {
+        PoolState {
+            curve_kind: self.curve_kind.into(),
+            total_supply: self.total_supply,
+            reserve_token: self.reserve_token,
+            reserve_sol: self.reserve_sol,
+            virtual_sol_reserves: self.virtual_sol_reserves,
+            proportion: self.proportion,
+        }
+    }
+
+    pub fn quote_buy(&self, amount: u64, fee_rate: FeeRate) -> Result<TradeQuote> {
+        Ok(self.curve_state().quote_buy(amount, fee_rate).map_err(CustomError::from)?)
+    }
+
+    pub fn quote_buy_exact_out(&self, token_amount: u64, fee_rate: FeeRate) -> Result<TradeQuote> {
+        Ok(self
+            .curve_state()
+            .quote_buy_exact_out(token_amount, fee_rate)
+            .map_err(CustomError::from)?)
+    }
+
+    pub fn quote_sell(&self, amount: u64, fee_rate: FeeRate) -> Result<TradeQuote> {
+        Ok(self.curve_state().quote_sell(amount, fee_rate).map_err(CustomError::from)?)
+    }
+
+    pub fn quote_sell_exact_out(&self, sol_amount: u64, fee_rate: FeeRate) -> Result<TradeQuote> {
+        Ok(self
+            .curve_state()
+            .quote_sell_exact_out(sol_amount, fee_rate)
+            .map_err(CustomError::from)?)
+    }
+
+    pub fn apply_buy(&mut self, quote: &TradeQuote) -> Result<()> {
+        let mut state = self.curve_state();
+        state.apply_buy(quote).map_err(CustomError::from)?;
+
+        self.reserve_token = state.reserve_token;
+        self.reserve_sol = state.reserve_sol;
+        Ok(())
+    }
 
-    // Discriminator (8) + Pubkey (32) + Pubkey (32) + totalsupply (8)
-    // + reserve one (8) + reserve two (8) + Bump (1)
-    pub const ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+    pub fn apply_sell(&mut self, quote: &TradeQuote) -> Result<()> {
+        let mut state = self.curve_state();
+        state.apply_sell(quote).map_err(CustomError::from)?;
 
-    // Constructor to initialize a LiquidityPool with two tokens and a bump for the PDA
-    pub fn new(creator: Pubkey, token: Pubkey, bump: u8) -> Self {
-        Self {
-            creator,
-            token,
-            total_supply: 0_u64,
-            reserve_token: 0_u64,
-            reserve_sol: 0_u64,
-            bump,
-        }
+        self.reserve_token = state.reserve_token;
+        self.reserve_sol = state.reserve_sol;
+        Ok(())
+    }
+
+    // Marginal price of one whole token (10^decimals base units) in lamports times `PRICE_SCALE`
+    pub fn spot_price(&self, decimals: u8) -> Result<u64> {
+        Ok(self.curve_state().spot_price(decimals).map_err(CustomError::from)?)
+    }
+
+    // Fully diluted market cap in lamports at the current spot price
+    pub fn market_cap(&self) -> Result<u64> {
+        Ok(self.curve_state().market_cap().map_err(CustomError::from)?)
     }
 }
 
+// Result of a simulated trade, returned by `quote_buy` and `quote_sell`. Quotes take no
+// trader, so `fee` and the amounts are for an undiscounted trade; a `FeeDiscount` or holder
+// discount only lowers the fee actually charged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct QuoteResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub reserve_sol: u64,
+    pub reserve_token: u64,
+    // Lamports per whole token times `PRICE_SCALE`
+    pub price_lamports_e9: u64,
+}
+
 pub trait LiquidityPoolAccount<'info> {
     // Updates the token reserves in the liquidity pool
     fn update_reserves(&mut self, reserve_token: u64, reserve_sol: u64) -> Result<()>;
@@ -89,6 +948,17 @@ pub trait LiquidityPoolAccount<'info> {
         system_program: &Program<'info, System>,
     ) -> Result<()>;
 
+    // Mints the fixed supply of a token created by `launch_token` into the pool, drops the mint
+    // authority so no more can be minted, and seeds the vault with the initial lamports
+    fn mint_liquidity(
+        &mut self,
+        token_accounts: (&mut Account<'info, Mint>, &mut Account<'info, TokenAccount>),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        user: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
     // Allows removing liquidity by burning pool shares and receiving back a proportionate amount of tokens
     fn remove_liquidity(
         &mut self,
@@ -104,6 +974,7 @@ pub trait LiquidityPoolAccount<'info> {
         system_program: &Program<'info, System>,
     ) -> Result<()>;
 
+    #[allow(clippy::too_many_arguments)]
     fn buy(
         &mut self,
         token_accounts: (
@@ -112,14 +983,41 @@ pub trait LiquidityPoolAccount<'info> {
             &mut Account<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
-        team_account: UncheckedAccount<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
         amount: u64,
+        min_tokens_out: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+        fees: TradeFees,
+    ) -> Result<()>;
+
+    // Buys exactly `token_amount` tokens, paying at most `max_sol_in` lamports including fees
+    #[allow(clippy::too_many_arguments)]
+    fn buy_exact_out(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        token_amount: u64,
+        max_sol_in: u64,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-        fees:f64
+        fees: TradeFees,
     ) -> Result<()>;
 
+    #[allow(clippy::too_many_arguments)]
     fn sell(
         &mut self,
         token_accounts: (
@@ -128,13 +1026,93 @@ pub trait LiquidityPoolAccount<'info> {
             &mut Account<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
-        team_account: UncheckedAccount<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
         amount: u64,
+        min_sol_out: u64,
+        bump: u8,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+        fees: TradeFees,
+    ) -> Result<()>;
+
+    // Sells just enough tokens to receive exactly `sol_amount` lamports after fees
+    #[allow(clippy::too_many_arguments)]
+    fn sell_exact_out(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        sol_amount: u64,
+        max_tokens_in: u64,
+        bump: u8,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+        fees: TradeFees,
+    ) -> Result<()>;
+
+    // Moves the funds for a quoted buy and records it
+    #[allow(clippy::too_many_arguments)]
+    fn execute_buy(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        quote: TradeQuote,
+        fees: TradeFees,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
+
+    // Moves the funds for a quoted sell and records it
+    #[allow(clippy::too_many_arguments)]
+    fn execute_sell(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        quote: TradeQuote,
+        fees: TradeFees,
         bump: u8,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-        fees:f64
+    ) -> Result<()>;
+
+    // Adds to the running total of creator fees paid into the pool's creator fee vault
+    fn accrue_creator_fee(&mut self, creator_fee: u64) -> Result<()>;
+
+    // Fails if the vault balances no longer cover the recorded reserves
+    fn check_reserves(
+        &self,
+        pool_token_account: &mut Account<'info, TokenAccount>,
+        pool_sol_vault: &AccountInfo<'info>,
     ) -> Result<()>;
 
     fn transfer_token_from_pool(
@@ -145,6 +1123,15 @@ pub trait LiquidityPoolAccount<'info> {
         token_program: &Program<'info, Token>,
     ) -> Result<()>;
 
+    // Burns tokens held by the pool
+    fn burn_token_from_pool(
+        &self,
+        token_mint: &Account<'info, Mint>,
+        from: &Account<'info, TokenAccount>,
+        amount: u64,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()>;
+
     fn transfer_token_to_pool(
         &self,
         from: &Account<'info, TokenAccount>,
@@ -170,6 +1157,15 @@ pub trait LiquidityPoolAccount<'info> {
         bump: u8,
         system_program: &Program<'info, System>,
     ) -> Result<()>;
+
+    fn transfer_fee_from_pool(
+        &self,
+        from: &mut AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bump: u8,
+        system_program: &Program<'info, System>,
+    ) -> Result<()>;
 }
 
 impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
@@ -203,13 +1199,56 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         self.transfer_sol_to_pool(
             user,
             pool_sol_vault,
-            INITIAL_LAMPORTS_FOR_POOL,
+            self.initial_lamports,
             system_program,
         )?;
-        self.total_supply = 1_000_000_000_000_000_000;
-        self.update_reserves(token_accounts.0.supply, INITIAL_LAMPORTS_FOR_POOL)?;
+        self.open_trading(token_accounts.0.supply, token_accounts.0.decimals)
+    }
+
+    fn mint_liquidity(
+        &mut self,
+        token_accounts: (&mut Account<'info, Mint>, &mut Account<'info, TokenAccount>),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        user: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let token = self.token;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
+            token.as_ref(),
+            &[self.bump],
+        ]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::MintTo {
+                    mint: token_accounts.0.to_account_info(),
+                    to: token_accounts.1.to_account_info(),
+                    authority: self.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            LiquidityPool::TOKEN_SUPPLY,
+        )?;
+        token::set_authority(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::SetAuthority {
+                    current_authority: self.to_account_info(),
+                    account_or_mint: token_accounts.0.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
 
-        Ok(())
+        self.transfer_sol_to_pool(user, pool_sol_vault, self.initial_lamports, system_program)?;
+
+        token_accounts.0.reload()?;
+        self.open_trading(token_accounts.0.supply, token_accounts.0.decimals)
     }
 
     fn remove_liquidity(
@@ -228,11 +1267,12 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         self.transfer_token_from_pool(
             token_accounts.1,
             token_accounts.2,
-            token_accounts.1.amount as u64,
+            token_accounts.1.amount,
             token_program,
         )?;
-        let amount = pool_sol_vault.to_account_info().lamports() as u64;
+        let amount = pool_sol_vault.to_account_info().lamports();
         self.transfer_sol_from_pool(pool_sol_vault, authority, amount, bump, system_program)?;
+        self.update_reserves(0, 0)?;
 
         Ok(())
     }
@@ -245,71 +1285,52 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             &mut Account<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
-        team_account: UncheckedAccount<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
         amount: u64,
+        min_tokens_out: u64,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-        fees: f64
+        fees: TradeFees,
     ) -> Result<()> {
-        if amount == 0 {
-            return err!(CustomError::InvalidAmount);
-        }
-        if self.reserve_token < amount {
-            return err!(CustomError::NotEnoughTokenInVault);
-        }
-
-    
-        let fee_amount = (amount as f64) * (fees / 100.0);
-        let adjusted_amount = (amount as f64) * (1.0 - fees / 100.0);
-
-        let fee_amount_u64 = fee_amount.round() as u64;
-        let adjusted_amount_u64 = adjusted_amount.round() as u64;
-    
-        let ix = transfer(authority.key, team_account.key, fee_amount_u64);
-        invoke(
-            &ix,
-            &[
-                authority.to_account_info(),
-                team_account.to_account_info(),
-                system_program.to_account_info(),
-            ],
-        )?;
-
-    
-        let virtual_sol = 25_000_000_000.0; 
-        
+        let quote = self.quote_buy(amount, fees.rate)?;
 
-        let bought_amount = (self.total_supply as f64 - self.reserve_token as f64) / 1_000_000.0 / 1_000_000_000.0 
-            + virtual_sol / 1_000_000_000.0;
-
-        let root_val = (PROPORTION as f64 * adjusted_amount as f64 / 1_000_000_000.0 + bought_amount * bought_amount).sqrt();
+        if quote.token_amount < min_tokens_out {
+            return Err(error!(CustomError::SlippageExceeded).with_values((min_tokens_out, quote.token_amount)));
+        }
 
-        let amount_out_f64 = (root_val - bought_amount) * 1_000_000.0 * 1_000_000_000.0;
+        self.execute_buy(token_accounts, pool_sol_vault, dex_config, protocol_treasury, creator_fee_vault, referrer, quote, fees, authority, token_program, system_program)
+    }
 
-        let amount_out = amount_out_f64.round() as u64;
+    fn buy_exact_out(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        token_amount: u64,
+        max_sol_in: u64,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+        fees: TradeFees,
+    ) -> Result<()> {
+        let quote = self.quote_buy_exact_out(token_amount, fees.rate)?;
 
-        if amount_out > self.reserve_token {
-            return err!(CustomError::NotEnoughTokenInVault);
+        if quote.sol_amount > max_sol_in {
+            return Err(error!(CustomError::SlippageExceeded).with_values((max_sol_in, quote.sol_amount)));
         }
 
-        self.reserve_sol += adjusted_amount_u64;
-        self.reserve_token -= amount_out;
-
-
-        self.transfer_sol_to_pool(authority, pool_sol_vault, adjusted_amount_u64, system_program)?;
-        self.transfer_token_from_pool(token_accounts.1, token_accounts.2, amount_out, token_program)?;
-        msg!("TRANSACTION_INFO{{\"token_mint_address\":\"{}\",\"type\":\"BUY\",\"sol_amount\":{},\"token_amount\":{},\"wallet\":\"{}\"}}",
-        token_accounts.0.key(),
-        amount,
-        amount_out,
-        authority.key()
-    );
-       msg!("CHART_DATA{{\"token_mint_address\":\"{}\", \"mcap\":{}}}",
-        token_accounts.0.key(),
-        (self.reserve_sol + virtual_sol.round() as u64)
-    );
-        Ok(())
+        self.execute_buy(token_accounts, pool_sol_vault, dex_config, protocol_treasury, creator_fee_vault, referrer, quote, fees, authority, token_program, system_program)
     }
 
     fn sell(
@@ -320,79 +1341,199 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
             &mut Account<'info, TokenAccount>,
         ),
         pool_sol_vault: &mut AccountInfo<'info>,
-        team_account: UncheckedAccount<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
         amount: u64,
+        min_sol_out: u64,
         bump: u8,
         authority: &Signer<'info>,
         token_program: &Program<'info, Token>,
         system_program: &Program<'info, System>,
-        fees: f64
+        fees: TradeFees,
     ) -> Result<()> {
-        if amount == 0 {
-            return err!(CustomError::InvalidAmount);
+        let quote = self.quote_sell(amount, fees.rate)?;
+
+        if quote.sol_amount < min_sol_out {
+            return Err(error!(CustomError::SlippageExceeded).with_values((min_sol_out, quote.sol_amount)));
         }
 
-        let virtual_sol = 25_000_000_000.0; 
+        self.execute_sell(token_accounts, pool_sol_vault, dex_config, protocol_treasury, creator_fee_vault, referrer, quote, fees, bump, authority, token_program, system_program)
+    }
 
-        let bought_amount = (self.total_supply as f64 - self.reserve_token as f64) / 1_000_000.0 / 1_000_000_000.0 
-            + virtual_sol / 1_000_000_000.0;
+    fn sell_exact_out(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        sol_amount: u64,
+        max_tokens_in: u64,
+        bump: u8,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+        fees: TradeFees,
+    ) -> Result<()> {
+        let quote = self.quote_sell_exact_out(sol_amount, fees.rate)?;
 
-        let result_amount = (self.total_supply as f64 - self.reserve_token as f64 - amount as f64) / 1_000_000.0 / 1_000_000_000.0 
-            + virtual_sol / 1_000_000_000.0;
+        if quote.token_amount > max_tokens_in {
+            return Err(error!(CustomError::SlippageExceeded).with_values((max_tokens_in, quote.token_amount)));
+        }
 
-        let amount_out_f64 = (bought_amount * bought_amount - result_amount * result_amount) / PROPORTION as f64 * 1_000_000_000.0;
+        self.execute_sell(token_accounts, pool_sol_vault, dex_config, protocol_treasury, creator_fee_vault, referrer, quote, fees, bump, authority, token_program, system_program)
+    }
 
+    fn execute_buy(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        quote: TradeQuote,
+        fees: TradeFees,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let sol_into_pool = quote.sol_amount - quote.fee;
+        let fee_split = fees.split(quote.fee, referrer.is_some())?;
 
-        if fees < 0.0 || fees > 100.0 {
-            return err!(CustomError::InvalidFeePercentage);
+        self.transfer_sol_to_pool(authority, protocol_treasury, fee_split.protocol, system_program)?;
+        dex_config.record_protocol_fee(fee_split.protocol)?;
+        self.transfer_sol_to_pool(authority, creator_fee_vault, fee_split.creator, system_program)?;
+        self.accrue_creator_fee(fee_split.creator)?;
+        if let Some(referrer) = referrer {
+            self.transfer_sol_to_pool(authority, &mut referrer.to_account_info(), fee_split.referral, system_program)?;
+            referrer.record(quote.sol_amount, fee_split.referral)?;
         }
 
-        let adjusted_amount = amount_out_f64 * (1.0 - fees / 100.0);
-        let fee_amount: f64 = amount_out_f64 * (fees / 100.0);
+        self.apply_buy(&quote)?;
 
- 
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                system_program.to_account_info(),
-                system_program::Transfer {
-                    from: pool_sol_vault.clone(),
-                    to: team_account.to_account_info().clone(),
-                },
-                &[&[
-                    LiquidityPool::SOL_VAULT_PREFIX.as_bytes(),
-                    self.token.key().as_ref(),
-                    &[bump],
-                ]],
-            ),
-            fee_amount.round() as u64,
-        )?;
+        self.transfer_sol_to_pool(authority, pool_sol_vault, sol_into_pool, system_program)?;
+        self.transfer_token_from_pool(token_accounts.1, token_accounts.2, quote.token_amount, token_program)?;
+        self.check_reserves(token_accounts.1, pool_sol_vault)?;
+        self.last_price = self.spot_price(token_accounts.0.decimals)?;
+        let market_cap = self.market_cap()?;
+        msg!("TRANSACTION_INFO{{\"token_mint_address\":\"{}\",\"type\":\"BUY\",\"sol_amount\":{},\"token_amount\":{},\"price_lamports_e9\":{},\"wallet\":\"{}\"}}",
+        token_accounts.0.key(),
+        quote.sol_amount,
+        quote.token_amount,
+        self.last_price,
+        authority.key()
+    );
+        msg!("CHART_DATA{{\"token_mint_address\":\"{}\", \"price_lamports_e9\":{}, \"mcap\":{}}}",
+        token_accounts.0.key(),
+        self.last_price,
+        market_cap
+    );
+
+        let graduation = self.graduation;
+        if graduation.reached(self)? {
+            self.status = PoolStatus::Graduated;
+            msg!("POOL_GRADUATED{{\"token_mint_address\":\"{}\",\"reserve_sol\":{},\"reserve_token\":{},\"mcap\":{}}}",
+                token_accounts.0.key(),
+                self.reserve_sol,
+                self.reserve_token,
+                market_cap
+            );
+        }
+        Ok(())
+    }
 
-        let amount_out = adjusted_amount.round() as u64;
+    fn execute_sell(
+        &mut self,
+        token_accounts: (
+            &mut Account<'info, Mint>,
+            &mut Account<'info, TokenAccount>,
+            &mut Account<'info, TokenAccount>,
+        ),
+        pool_sol_vault: &mut AccountInfo<'info>,
+        dex_config: &mut Account<'info, CurveConfiguration>,
+        protocol_treasury: &mut AccountInfo<'info>,
+        creator_fee_vault: &mut AccountInfo<'info>,
+        referrer: Option<&mut Account<'info, Referrer>>,
+        quote: TradeQuote,
+        fees: TradeFees,
+        bump: u8,
+        authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let fee_split = fees.split(quote.fee, referrer.is_some())?;
 
-        if self.reserve_sol < amount_out {
-            return err!(CustomError::NotEnoughSolInVault);
+        self.transfer_fee_from_pool(pool_sol_vault, protocol_treasury.clone(), fee_split.protocol, bump, system_program)?;
+        dex_config.record_protocol_fee(fee_split.protocol)?;
+        self.transfer_fee_from_pool(pool_sol_vault, creator_fee_vault.clone(), fee_split.creator, bump, system_program)?;
+        self.accrue_creator_fee(fee_split.creator)?;
+        if let Some(referrer) = referrer {
+            self.transfer_fee_from_pool(pool_sol_vault, referrer.to_account_info(), fee_split.referral, bump, system_program)?;
+            referrer.record(quote.sol_amount + quote.fee, fee_split.referral)?;
         }
 
- 
-        self.transfer_token_to_pool(token_accounts.2, token_accounts.1, amount, authority, token_program)?;
+        self.transfer_token_to_pool(token_accounts.2, token_accounts.1, quote.token_amount, authority, token_program)?;
 
-        self.reserve_token += amount;
-        self.reserve_sol -= amount_out;
+        self.apply_sell(&quote)?;
 
-        self.transfer_sol_from_pool(pool_sol_vault, authority, amount_out, bump, system_program)?;
-        msg!("TRANSACTION_INFO{{\"token_mint_address\":\"{}\",\"type\":\"SELL\",\"sol_amount\":{},\"token_amount\":{},\"wallet\":\"{}\"}}",
+        self.transfer_sol_from_pool(pool_sol_vault, authority, quote.sol_amount, bump, system_program)?;
+        self.check_reserves(token_accounts.1, pool_sol_vault)?;
+        self.last_price = self.spot_price(token_accounts.0.decimals)?;
+        let market_cap = self.market_cap()?;
+        msg!("TRANSACTION_INFO{{\"token_mint_address\":\"{}\",\"type\":\"SELL\",\"sol_amount\":{},\"token_amount\":{},\"price_lamports_e9\":{},\"wallet\":\"{}\"}}",
         token_accounts.0.key(),
-        amount_out,
-        amount,
+        quote.sol_amount,
+        quote.token_amount,
+        self.last_price,
         authority.key()
     );
-        msg!("CHART_DATA{{\"token_mint_address\":\"{}\", \"mcap\":{}}}",
+        msg!("CHART_DATA{{\"token_mint_address\":\"{}\", \"price_lamports_e9\":{}, \"mcap\":{}}}",
         token_accounts.0.key(),
-        (self.reserve_sol + virtual_sol.round() as u64)
+        self.last_price,
+        market_cap
     );
         Ok(())
     }
 
+    fn accrue_creator_fee(&mut self, creator_fee: u64) -> Result<()> {
+        self.creator_fees_accrued = self
+            .creator_fees_accrued
+            .checked_add(creator_fee)
+            .ok_or(CustomError::OverflowOrUnderflowOccurred)?;
+        Ok(())
+    }
+
+    fn check_reserves(
+        &self,
+        pool_token_account: &mut Account<'info, TokenAccount>,
+        pool_sol_vault: &AccountInfo<'info>,
+    ) -> Result<()> {
+        // The token account was changed by a CPI, so its cached amount is stale
+        pool_token_account.reload()?;
+
+        let vault_lamports = pool_sol_vault.lamports();
+        if vault_lamports < self.reserve_sol {
+            return Err(error!(CustomError::ReservesOutOfSync).with_values((self.reserve_sol, vault_lamports)));
+        }
+        if pool_token_account.amount < self.reserve_token {
+            return Err(error!(CustomError::ReservesOutOfSync)
+                .with_values((self.reserve_token, pool_token_account.amount)));
+        }
+        Ok(())
+    }
+
     fn transfer_token_from_pool(
         &self,
         from: &Account<'info, TokenAccount>,
@@ -419,6 +1560,31 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         Ok(())
     }
 
+    fn burn_token_from_pool(
+        &self,
+        token_mint: &Account<'info, Mint>,
+        from: &Account<'info, TokenAccount>,
+        amount: u64,
+        token_program: &Program<'info, Token>,
+    ) -> Result<()> {
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Burn {
+                    mint: token_mint.to_account_info(),
+                    from: from.to_account_info(),
+                    authority: self.to_account_info(),
+                },
+                &[&[
+                    LiquidityPool::POOL_SEED_PREFIX.as_bytes(),
+                    self.token.key().as_ref(),
+                    &[self.bump],
+                ]],
+            ),
+            amount,
+        )
+    }
+
     fn transfer_token_to_pool(
         &self,
         from: &Account<'info, TokenAccount>,
@@ -468,6 +1634,32 @@ impl<'info> LiquidityPoolAccount<'info> for Account<'info, LiquidityPool> {
         Ok(())
     }
 
+    fn transfer_fee_from_pool(
+        &self,
+        from: &mut AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bump: u8,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: from.clone(),
+                    to,
+                },
+                &[&[
+                    LiquidityPool::SOL_VAULT_PREFIX.as_bytes(),
+                    self.token.key().as_ref(),
+                    &[bump],
+                ]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+
     fn transfer_sol_to_pool(
         &self,
         from: &Signer<'info>,
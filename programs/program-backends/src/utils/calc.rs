@@ -1,9 +0,0 @@
-use std::ops::{Div, Mul};
-
-pub fn convert_to_float(value: u64, decimals: u8) -> f64 {
-    (value as f64).div(f64::powf(10.0, decimals as f64))
-}
-
-pub fn convert_from_float(value: f64, decimals: u8) -> u64 {
-    value.mul(f64::powf(10.0, decimals as f64)) as u64
-}
\ No newline at end of file
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CustomError;
+
+// Shape of the bonding curve a pool trades on. The maths for each kind lives in the
+// `curve-math` crate, which documents how `virtual_sol_reserves` and `proportion` are used.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveKind {
+    Quadratic,
+    ConstantProduct,
+    Linear,
+    Exponential,
+}
+
+impl From<CurveKind> for curve_math::CurveKind {
+    fn from(kind: CurveKind) -> Self {
+        match kind {
+            CurveKind::Quadratic => curve_math::CurveKind::Quadratic,
+            CurveKind::ConstantProduct => curve_math::CurveKind::ConstantProduct,
+            CurveKind::Linear => curve_math::CurveKind::Linear,
+            CurveKind::Exponential => curve_math::CurveKind::Exponential,
+        }
+    }
+}
+
+impl CurveKind {
+    pub fn validate(&self, proportion: u64) -> Result<()> {
+        curve_math::CurveKind::from(*self)
+            .validate(proportion)
+            .map_err(CustomError::from)?;
+        Ok(())
+    }
+}
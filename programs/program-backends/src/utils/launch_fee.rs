@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CustomError;
+
+// What the launch window of a `LaunchFeeSchedule` is measured in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchClock {
+    Slots,
+    Seconds,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchDecay {
+    Linear,
+    Exponential,
+}
+
+impl From<LaunchDecay> for curve_math::LaunchDecay {
+    fn from(decay: LaunchDecay) -> Self {
+        match decay {
+            LaunchDecay::Linear => curve_math::LaunchDecay::Linear,
+            LaunchDecay::Exponential => curve_math::LaunchDecay::Exponential,
+        }
+    }
+}
+
+// Anti-sniper fee for the first `duration` slots or seconds after `add_liquidity`. Trades
+// start at `start_fee_bps` and the fee decays to the base buy or sell fee by the end of the
+// window. A `start_fee_bps` of zero disables it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct LaunchFeeSchedule {
+    pub start_fee_bps: u16,
+    pub duration: u64,
+    pub clock: LaunchClock,
+    pub decay: LaunchDecay,
+}
+
+impl LaunchFeeSchedule {
+    pub const SIZE: usize = 2 + 8 + 1 + 1;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.start_fee_bps as u64 > curve_math::BPS_DENOMINATOR
+            || (self.start_fee_bps > 0 && self.duration == 0)
+        {
+            return err!(CustomError::InvalidFeePercentage);
+        }
+        Ok(())
+    }
+
+    // Fee in bps for a trade now, for a pool launched at `launch_slot` / `launch_timestamp`
+    pub fn fee_bps(&self, base_bps: u16, launch_slot: u64, launch_timestamp: i64) -> Result<u16> {
+        let clock = Clock::get()?;
+        let elapsed = match self.clock {
+            LaunchClock::Slots => clock.slot.saturating_sub(launch_slot),
+            LaunchClock::Seconds => clock.unix_timestamp.saturating_sub(launch_timestamp).max(0) as u64,
+        };
+
+        Ok(curve_math::launch_fee_bps(
+            base_bps,
+            self.start_fee_bps,
+            elapsed,
+            self.duration,
+            self.decay.into(),
+        ))
+    }
+}
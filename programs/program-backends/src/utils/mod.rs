@@ -1,2 +1,4 @@
-pub mod calc;
-pub use calc::*;
\ No newline at end of file
+pub mod curve;
+pub mod launch_fee;
+pub use curve::*;
+pub use launch_fee::*;
@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use ai_agent::{
+    state::{GraduationSettings, LiquidityPool},
+    utils::{CurveKind, LaunchClock, LaunchDecay, LaunchFeeSchedule},
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::SUCCESS, program_pack::Pack, program_stubs},
+};
+
+// Backing storage for one account handed to `try_accounts`
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    pub fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self { key, owner, lamports: 1_000_000_000, data, is_signer: false, executable: false }
+    }
+
+    pub fn program(key: Pubkey) -> Self {
+        Self { executable: true, ..Self::new(key, Pubkey::default(), vec![]) }
+    }
+
+    pub fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            true,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+// A pool on `mint` seeded with the full supply and `initial_lamports`, as `launch_token` leaves it
+pub fn launched_pool(creator: Pubkey, mint: Pubkey, curve_kind: CurveKind, proportion: u64) -> LiquidityPool {
+    let (_, bump) = Pubkey::find_program_address(
+        &[LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.as_ref()],
+        &ai_agent::ID,
+    );
+    let mut pool = LiquidityPool::new(
+        creator,
+        mint,
+        bump,
+        30_000_000_000,
+        proportion,
+        1_000_000_000,
+        curve_kind,
+        LaunchFeeSchedule { start_fee_bps: 0, duration: 0, clock: LaunchClock::Slots, decay: LaunchDecay::Linear },
+        GraduationSettings::default(),
+    );
+    pool.total_supply = LiquidityPool::TOKEN_SUPPLY;
+    pool.reserve_token = LiquidityPool::TOKEN_SUPPLY;
+    pool.reserve_sol = pool.initial_lamports;
+    pool
+}
+
+// `account` as the program stores it, in an allocation of `size` bytes
+pub fn account_data<T: AccountSerialize>(account: &T, size: usize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.resize(size, 0);
+    data
+}
+
+pub fn pool_data(pool: &LiquidityPool) -> Vec<u8> {
+    account_data(pool, LiquidityPool::ACCOUNT_SIZE)
+}
+
+// Key of the program's PDA for `seeds`
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ai_agent::ID).0
+}
+
+pub fn mint_data(authority: Pubkey) -> Vec<u8> {
+    let mint = spl_token::state::Mint {
+        mint_authority: Some(authority).into(),
+        supply: LiquidityPool::TOKEN_SUPPLY,
+        decimals: LiquidityPool::TOKEN_DECIMALS,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    mint.pack_into_slice(&mut data);
+    data
+}
+
+pub fn token_account_data(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    let account = spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    };
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    account.pack_into_slice(&mut data);
+    data
+}
+
+pub fn rent_data() -> Vec<u8> {
+    let rent = Rent::default();
+    let mut data = Vec::new();
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+struct ClockStub(Clock);
+
+impl program_stubs::SyscallStubs for ClockStub {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { var_addr.cast::<Clock>().write(self.0.clone()) };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { var_addr.cast::<Rent>().write(Rent::default()) };
+        SUCCESS
+    }
+}
+
+// Makes `Clock::get` and `Rent::get` work outside the runtime. The stubs are shared by every
+// test in the binary, so tests in one file have to agree on the clock.
+pub fn set_clock(slot: u64, unix_timestamp: i64) {
+    program_stubs::set_syscall_stubs(Box::new(ClockStub(Clock { slot, unix_timestamp, ..Clock::default() })));
+}
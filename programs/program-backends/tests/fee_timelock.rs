@@ -0,0 +1,150 @@
+use std::collections::BTreeSet;
+
+use ai_agent::{
+    errors::CustomError,
+    instructions::{
+        execute_pool_fee_override, ExecutePoolFeeOverride, ExecutePoolFeeOverrideBumps, SetPoolFeeOverride,
+        SetPoolFeeOverrideBumps,
+    },
+    state::{
+        ConfigUpdate, CurveConfiguration, CurveLimits, FeeSettings, HolderDiscount, LiquidityPool,
+        PendingPoolFeeOverride, PoolFeeOverride,
+    },
+    utils::{CurveKind, LaunchClock, LaunchDecay, LaunchFeeSchedule},
+};
+use anchor_lang::{prelude::*, system_program, Accounts};
+use anchor_spl::token;
+
+mod common;
+use common::*;
+
+const NOW: i64 = 1_700_000_000;
+const DELAY: i64 = 3_600;
+const FEE_OVERRIDE: PoolFeeOverride = PoolFeeOverride { buy_fee_bps: 40, sell_fee_bps: 60 };
+
+fn timelocked_config(admin: Pubkey) -> CurveConfiguration {
+    let fees = FeeSettings {
+        buy_fee_bps: 100,
+        sell_fee_bps: 100,
+        min_fee_lamports: 0,
+        creator_fee_share_bps: 0,
+        referral_fee_share_bps: 0,
+        launch_fee: LaunchFeeSchedule { start_fee_bps: 0, duration: 0, clock: LaunchClock::Slots, decay: LaunchDecay::Linear },
+    };
+    let curve_limits = CurveLimits {
+        min_virtual_sol_reserves: 1,
+        max_virtual_sol_reserves: u64::MAX,
+        min_proportion: 1,
+        max_proportion: u64::MAX,
+        min_initial_lamports: 0,
+        max_initial_lamports: u64::MAX,
+    };
+    let mut config = CurveConfiguration::new(fees, curve_limits, admin, Pubkey::new_unique());
+    config.config_change_delay = DELAY;
+    config
+}
+
+// The configuration, pool, mint and admin every fee override instruction starts with
+fn pool_accounts(admin: Pubkey, mint: Pubkey) -> Vec<TestAccount> {
+    let pool_key = pda(&[LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.as_ref()]);
+    let config = timelocked_config(admin);
+    vec![
+        TestAccount::new(
+            pda(&[CurveConfiguration::SEED.as_bytes()]),
+            ai_agent::ID,
+            account_data(&config, CurveConfiguration::ACCOUNT_SIZE),
+        ),
+        TestAccount::new(pool_key, ai_agent::ID, pool_data(&launched_pool(admin, mint, CurveKind::Linear, 50_000))),
+        TestAccount::new(mint, token::ID, mint_data(pool_key)),
+    ]
+}
+
+fn signer(key: Pubkey) -> TestAccount {
+    TestAccount { is_signer: true, ..TestAccount::new(key, system_program::ID, vec![]) }
+}
+
+// Runs `execute_pool_fee_override` on a queued override that becomes executable at
+// `executable_at`, returning the pool's fee override afterwards
+fn execute_queued_override(executable_at: i64) -> Result<Option<PoolFeeOverride>> {
+    set_clock(0, NOW);
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (pending_key, pending_bump) = Pubkey::find_program_address(
+        &[PendingPoolFeeOverride::SEED_PREFIX.as_bytes(), mint.as_ref()],
+        &ai_agent::ID,
+    );
+    let pending = PendingPoolFeeOverride {
+        fee_override: Some(FEE_OVERRIDE),
+        queued_at: executable_at - DELAY,
+        executable_at,
+        bump: pending_bump,
+    };
+
+    let mut accounts = pool_accounts(admin, mint);
+    accounts.push(TestAccount::new(
+        pending_key,
+        ai_agent::ID,
+        account_data(&pending, PendingPoolFeeOverride::ACCOUNT_SIZE),
+    ));
+    accounts.push(signer(admin));
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+    let mut remaining: &[AccountInfo] = &infos;
+    let mut bumps = ExecutePoolFeeOverrideBumps::default();
+    let mut execute =
+        ExecutePoolFeeOverride::try_accounts(&ai_agent::ID, &mut remaining, &[], &mut bumps, &mut BTreeSet::new())?;
+    execute_pool_fee_override(Context::new(&ai_agent::ID, &mut execute, &[], bumps))?;
+    Ok(execute.pool.fee_override)
+}
+
+#[test]
+fn fee_overrides_cannot_be_set_directly_while_timelocked() {
+    let admin = Pubkey::new_unique();
+    let mut accounts = pool_accounts(admin, Pubkey::new_unique());
+    accounts.push(signer(admin));
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+    let mut remaining: &[AccountInfo] = &infos;
+    let error = SetPoolFeeOverride::try_accounts(
+        &ai_agent::ID,
+        &mut remaining,
+        &[],
+        &mut SetPoolFeeOverrideBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+    .unwrap_err();
+    assert_eq!(error, CustomError::ConfigTimelocked.into());
+}
+
+#[test]
+fn queued_fee_overrides_apply_once_the_delay_has_passed() {
+    let error = execute_queued_override(NOW + 1).unwrap_err();
+    assert_eq!(error, CustomError::ConfigChangeNotReady.into());
+
+    assert_eq!(execute_queued_override(NOW).unwrap(), Some(FEE_OVERRIDE));
+}
+
+#[test]
+fn holder_discounts_are_part_of_queued_config_changes() {
+    let mut config = timelocked_config(Pubkey::new_unique());
+    let holder_discount = HolderDiscount { platform_mint: Pubkey::new_unique(), min_balance: 1_000, discount_bps: 2_500 };
+    let update = ConfigUpdate {
+        fees: None,
+        curve_limits: None,
+        fee_recipient: None,
+        config_change_delay: None,
+        graduation: None,
+        holder_discount: Some(holder_discount),
+    };
+    update.validate().unwrap();
+    config.apply(&update);
+    assert_eq!(config.holder_discount.platform_mint, holder_discount.platform_mint);
+    assert_eq!(config.holder_discount.discount_bps, 2_500);
+
+    let invalid = ConfigUpdate {
+        holder_discount: Some(HolderDiscount { discount_bps: 10_001, ..holder_discount }),
+        ..update
+    };
+    assert_eq!(invalid.validate().unwrap_err(), CustomError::InvalidFeePercentage.into());
+}
@@ -0,0 +1,58 @@
+use ai_agent::{
+    state::{FeeSettings, LiquidityPool},
+    utils::{CurveKind, LaunchClock, LaunchDecay, LaunchFeeSchedule},
+};
+use anchor_lang::prelude::*;
+
+mod common;
+use common::*;
+
+const LAUNCH_SLOT: u64 = 1_000;
+
+fn settings() -> FeeSettings {
+    FeeSettings {
+        buy_fee_bps: 100,
+        sell_fee_bps: 100,
+        min_fee_lamports: 0,
+        creator_fee_share_bps: 0,
+        referral_fee_share_bps: 0,
+        launch_fee: LaunchFeeSchedule { start_fee_bps: 0, duration: 0, clock: LaunchClock::Slots, decay: LaunchDecay::Linear },
+    }
+}
+
+fn launching_pool() -> LiquidityPool {
+    let mut pool = launched_pool(Pubkey::new_unique(), Pubkey::new_unique(), CurveKind::Linear, 50_000);
+    pool.launch_fee = LaunchFeeSchedule {
+        start_fee_bps: 5_000,
+        duration: 150,
+        clock: LaunchClock::Slots,
+        decay: LaunchDecay::Linear,
+    };
+    pool.launch_slot = LAUNCH_SLOT;
+    pool
+}
+
+#[test]
+fn snipers_pay_the_launch_fee() {
+    set_clock(LAUNCH_SLOT, 0);
+    let pool = launching_pool();
+
+    assert_eq!(pool.buy_fees(&settings(), 0, false).unwrap().rate.bps, 5_000);
+    assert_eq!(pool.sell_fees(&settings(), 0).unwrap().rate.bps, 5_000);
+}
+
+#[test]
+fn the_creators_launch_buy_skips_the_launch_fee() {
+    set_clock(LAUNCH_SLOT, 0);
+    let pool = launching_pool();
+
+    let fees = pool.buy_fees(&settings(), 0, true).unwrap();
+    assert_eq!(fees.rate.bps, 100);
+    let discounted = pool.buy_fees(&settings(), 5_000, true).unwrap();
+    assert_eq!(discounted.rate.bps, 50);
+
+    let creator_buy = pool.quote_buy(1_000_000_000, fees.rate).unwrap();
+    let sniper_buy = pool.quote_buy(1_000_000_000, pool.buy_fees(&settings(), 0, false).unwrap().rate).unwrap();
+    assert_eq!(creator_buy.fee, 10_000_000);
+    assert!(creator_buy.token_amount > sniper_buy.token_amount);
+}
@@ -0,0 +1,58 @@
+use ai_agent::{
+    instructions::migrated_pool,
+    state::{GraduationSettings, LiquidityPool},
+};
+use anchor_lang::{prelude::*, Discriminator};
+use curve_math::FeeRate;
+
+const FEE_RATE: FeeRate = FeeRate { bps: 100, min_lamports: 0 };
+
+// A pool in the original program's layout, with the SOL reserve it recorded
+fn legacy_pool_data(mint: Pubkey, reserve_token: u64, reserve_sol: u64) -> Vec<u8> {
+    let (_, bump) = Pubkey::find_program_address(
+        &[LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.as_ref()],
+        &ai_agent::ID,
+    );
+    let mut data = LiquidityPool::discriminator().to_vec();
+    (Pubkey::new_unique(), mint, LiquidityPool::TOKEN_SUPPLY, reserve_token, reserve_sol, bump)
+        .serialize(&mut data)
+        .unwrap();
+    data
+}
+
+#[test]
+fn migrated_pools_take_their_sol_reserve_from_the_vault() {
+    let rent = Rent::default();
+    let rent_exempt = rent.minimum_balance(0);
+    let mint = Pubkey::new_unique();
+
+    // Tokens the original pool sold for 5 SOL, which it recorded alongside its virtual reserve
+    let fresh = migrated_pool(
+        &legacy_pool_data(mint, LiquidityPool::TOKEN_SUPPLY, 0),
+        GraduationSettings::default(),
+        rent_exempt,
+        &rent,
+        9,
+    )
+    .unwrap();
+    let sold = fresh.quote_buy(5_000_000_000, FeeRate { bps: 0, min_lamports: 0 }).unwrap().token_amount;
+    let legacy = legacy_pool_data(mint, LiquidityPool::TOKEN_SUPPLY - sold, 30_000_000_000);
+
+    let mut vault_lamports = rent_exempt + 5_000_000_000;
+    let mut pool = migrated_pool(&legacy, GraduationSettings::default(), vault_lamports, &rent, 9).unwrap();
+    assert_eq!(pool.reserve_sol, 5_000_000_000);
+    assert_eq!(pool.reserve_token, LiquidityPool::TOKEN_SUPPLY - sold);
+    assert_eq!(pool.last_price, pool.spot_price(9).unwrap());
+
+    let buy = pool.quote_buy(1_000_000_000, FEE_RATE).unwrap();
+    pool.apply_buy(&buy).unwrap();
+    vault_lamports += buy.sol_amount - buy.fee;
+    assert!(vault_lamports >= pool.reserve_sol, "buy left the pool out of sync with its vault");
+
+    let sell = pool.quote_sell(buy.token_amount + sold, FEE_RATE).unwrap();
+    pool.apply_sell(&sell).unwrap();
+    vault_lamports -= sell.sol_amount + sell.fee;
+    assert!(vault_lamports >= pool.reserve_sol, "sell left the pool out of sync with its vault");
+    assert!(vault_lamports >= rent_exempt, "selling every token drained the vault's rent");
+    assert_eq!(pool.reserve_token, LiquidityPool::TOKEN_SUPPLY);
+}
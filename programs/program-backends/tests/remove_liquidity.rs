@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use ai_agent::{
+    errors::CustomError,
+    instructions::{RemoveLiquidity, RemoveLiquidityBumps},
+    state::{LiquidityPool, PoolStatus},
+    utils::CurveKind,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar,
+    system_program, Accounts,
+};
+use anchor_spl::{associated_token, token};
+
+mod common;
+use common::*;
+
+// Runs the `RemoveLiquidity` account checks for the creator of a pool in `status`
+fn remove_liquidity_accounts(status: PoolStatus) -> Result<()> {
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (pool_key, _) = Pubkey::find_program_address(
+        &[LiquidityPool::POOL_SEED_PREFIX.as_bytes(), mint.as_ref()],
+        &ai_agent::ID,
+    );
+    let (sol_vault, _) = Pubkey::find_program_address(
+        &[LiquidityPool::SOL_VAULT_PREFIX.as_bytes(), mint.as_ref()],
+        &ai_agent::ID,
+    );
+
+    let mut pool = launched_pool(creator, mint, CurveKind::Linear, 50_000);
+    pool.reserve_token = LiquidityPool::TOKEN_SUPPLY / 2;
+    pool.reserve_sol = 80_000_000_000;
+    pool.status = status;
+
+    let mut accounts = [
+        TestAccount::new(pool_key, ai_agent::ID, pool_data(&pool)),
+        TestAccount::new(mint, token::ID, mint_data(pool_key)),
+        TestAccount::new(
+            associated_token::get_associated_token_address(&pool_key, &mint),
+            token::ID,
+            token_account_data(mint, pool_key, pool.reserve_token),
+        ),
+        TestAccount::new(
+            associated_token::get_associated_token_address(&creator, &mint),
+            token::ID,
+            token_account_data(mint, creator, 0),
+        ),
+        TestAccount::new(sol_vault, system_program::ID, vec![]),
+        TestAccount { is_signer: true, ..TestAccount::new(creator, system_program::ID, vec![]) },
+        TestAccount::new(sysvar::rent::ID, sysvar::ID, rent_data()),
+        TestAccount::program(system_program::ID),
+        TestAccount::program(token::ID),
+        TestAccount::program(associated_token::ID),
+    ];
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+    let mut remaining: &[AccountInfo] = &infos;
+    let mut bumps = RemoveLiquidityBumps::default();
+    RemoveLiquidity::try_accounts(&ai_agent::ID, &mut remaining, &[], &mut bumps, &mut BTreeSet::new())
+        .map(|_| ())
+}
+
+#[test]
+fn creator_can_remove_liquidity_while_trading() {
+    remove_liquidity_accounts(PoolStatus::Trading).unwrap();
+}
+
+#[test]
+fn graduated_pools_cannot_be_drained() {
+    for status in [PoolStatus::Graduated, PoolStatus::Migrated] {
+        let error = remove_liquidity_accounts(status).unwrap_err();
+        assert_eq!(error, CustomError::PoolGraduated.into());
+    }
+}